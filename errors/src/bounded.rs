@@ -0,0 +1,209 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+// Guess는 1..=100 검사를 i32 하나에 대해서만 하고 있었다.
+// 검사하는 범위와 정수 타입을 타입 인자로 받으면 같은 코드를 여러 범위에 다시 쓸 수 있다.
+//
+//   type Percent = Bounded<u8, 0, 100>;
+//   type Guess = Bounded<i32, 1, 100>;
+//
+// const generic의 타입은 다른 타입 인자(T)에 기댈 수 없기 때문에, 범위는 모든 정수를 담을 수 있는 i128로 받는다.
+// 그래서 u128은 지원하지 않는다.
+
+// Bounded에 넣을 수 있는 정수 타입들. 범위 검사와 계산은 i128로 바꿔서 한다.
+pub trait Integer:
+    Copy + PartialOrd + fmt::Debug + fmt::Display + FromStr<Err = ParseIntError>
+{
+    const LOWEST: i128;
+    const HIGHEST: i128;
+
+    fn to_i128(self) -> i128;
+
+    // LOWEST..=HIGHEST 안의 값만 넘겨야 한다.
+    fn from_i128(value: i128) -> Self;
+}
+
+// 타입마다 똑같은 impl을 써야 하므로 매크로로 만든다.
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const LOWEST: i128 = <$t>::MIN as i128;
+                const HIGHEST: i128 = <$t>::MAX as i128;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> $t {
+                    value as $t
+                }
+            }
+
+            // TryFrom<T>를 T에 대해 한 번에 구현하면 표준 라이브러리의
+            // impl<T, U: Into<T>> TryFrom<U> for T 와 겹칠 수 있다고 거절당하므로, 타입마다 따로 구현한다.
+            impl<const MIN: i128, const MAX: i128> TryFrom<$t> for Bounded<$t, MIN, MAX> {
+                type Error = BoundedError<$t>;
+
+                fn try_from(value: $t) -> Result<Self, BoundedError<$t>> {
+                    Bounded::try_new(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// MIN..=MAX 안의 값만 담을 수 있는 정수.
+// value가 private이라서 아래의 생성자를 거치지 않고는 만들 수 없다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128> {
+    value: T,
+}
+
+// Bounded를 만들 수 없을 때의 에러.
+// 숫자에서 만들 때(try_new)와 문자열에서 만들 때(parse) 모두 이 enum 하나로 돌려주므로,
+// 호출하는 쪽에서는 ?로 그대로 넘기거나 match 한 번으로 모든 경우를 처리할 수 있다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoundedError<T> {
+    // MIN보다 작다.
+    TooLow { min: T, got: T },
+    // MAX보다 크다.
+    TooHigh { max: T, got: T },
+    // 문자열이 T로 읽을 수 있는 숫자가 아니다.
+    Invalid(ParseIntError),
+}
+
+impl<T: fmt::Display> fmt::Display for BoundedError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoundedError::TooLow { min, got } => {
+                write!(f, "value must be at least {}, got {}", min, got)
+            }
+            BoundedError::TooHigh { max, got } => {
+                write!(f, "value must be at most {}, got {}", max, got)
+            }
+            BoundedError::Invalid(e) => write!(f, "not a valid number: {}", e),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> Error for BoundedError<T> {
+    // 원인이 된 에러를 돌려주면, 에러를 출력하는 쪽에서 원인까지 따라가며 보여줄 수 있다.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BoundedError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Integer, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+    // 범위가 잘못되었으면(MIN > MAX이거나 T에 담을 수 없으면) 컴파일할 때 에러가 난다.
+    // 이 상수는 사용될 때 계산되므로, 모든 생성자에서 한 번씩 사용한다.
+    const VALID: () = assert!(
+        T::LOWEST <= MIN && MIN <= MAX && MAX <= T::HIGHEST,
+        "Bounded needs MIN <= MAX, both representable in T"
+    );
+
+    pub fn min() -> T {
+        T::from_i128(MIN)
+    }
+
+    pub fn max() -> T {
+        T::from_i128(MAX)
+    }
+
+    pub fn try_new(value: T) -> Result<Self, BoundedError<T>> {
+        let () = Self::VALID;
+        if value.to_i128() < MIN {
+            Err(BoundedError::TooLow {
+                min: Self::min(),
+                got: value,
+            })
+        } else if value.to_i128() > MAX {
+            Err(BoundedError::TooHigh {
+                max: Self::max(),
+                got: value,
+            })
+        } else {
+            Ok(Bounded { value })
+        }
+    }
+
+    // 원래의 Guess::new처럼, 범위를 벗어나면 panic한다.
+    // 미리 검사하기 어렵다면 panic하지 않는 try_new를 사용하자.
+    pub fn new(value: T) -> Self {
+        if Self::try_new(value).is_err() {
+            panic!(
+                "value must be between {} and {}, got {}.",
+                Self::min(),
+                Self::max(),
+                value
+            );
+        }
+        Bounded { value }
+    }
+
+    // 범위를 벗어나면 가까운 끝 값으로 바꾼다.
+    pub fn clamped(value: T) -> Self {
+        Self::saturating_from(value)
+    }
+
+    // 다른 정수 타입의 값도 받는다. T에 담을 수 없을 만큼 큰 값이어도 끝 값이 된다.
+    pub fn saturating_from<U: Integer>(value: U) -> Self {
+        let () = Self::VALID;
+        let value = value.to_i128().clamp(MIN, MAX);
+        Bounded {
+            value: T::from_i128(value),
+        }
+    }
+
+    // getter와 같은 역할을 한다.
+    // 이는 value가 private하기 때문인데, private한 이유는
+    // 생성자를 이용하지 않고 직접적으로 값을 넣어주는 것을 방지하기 위해서이다.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    // 계산 결과가 범위를 벗어나면 None이다. T 자체가 넘치는 경우도 마찬가지다.
+    pub fn checked_add(self, rhs: T) -> Option<Self> {
+        Self::from_wide(self.value.to_i128().checked_add(rhs.to_i128()))
+    }
+
+    pub fn checked_sub(self, rhs: T) -> Option<Self> {
+        Self::from_wide(self.value.to_i128().checked_sub(rhs.to_i128()))
+    }
+
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        Self::from_wide(self.value.to_i128().checked_mul(rhs.to_i128()))
+    }
+
+    fn from_wide(value: Option<i128>) -> Option<Self> {
+        value
+            .filter(|value| (MIN..=MAX).contains(value))
+            .map(|value| Bounded {
+                value: T::from_i128(value),
+            })
+    }
+}
+
+impl<T: Integer, const MIN: i128, const MAX: i128> FromStr for Bounded<T, MIN, MAX> {
+    type Err = BoundedError<T>;
+
+    // 숫자로 읽는 에러와 범위 에러를 같은 BoundedError로 돌려준다.
+    fn from_str(s: &str) -> Result<Self, BoundedError<T>> {
+        let value: T = s.trim().parse().map_err(BoundedError::Invalid)?;
+        Bounded::try_new(value)
+    }
+}
+
+impl<T: fmt::Display, const MIN: i128, const MAX: i128> fmt::Display for Bounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use toml_edit::{ImDocument, Item};
+
+// read_to_string("hello.txt")로 파일을 통째로 읽던 것을 설정 파일로 넓혀본다.
+// 설정은 여러 층으로 쌓고, 나중에 쌓은 것이 앞의 것을 덮어쓴다.
+//
+//   TOML 파일 < 환경 변수 < 커맨드라인 인자
+//
+// 값을 꺼낼 때 원하는 타입으로 바꾸고, 실패하면 어디(파일과 줄, 환경 변수, 인자)의
+// 어떤 키가 어떤 타입이어야 했는지를 담은 ConfigError를 돌려준다.
+
+// 값이 어디에서 왔는지
+#[derive(Debug, Clone, PartialEq)]
+pub enum Origin {
+    File { path: PathBuf, line: usize },
+    Env { var: String },
+    Flag { flag: String },
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Origin::File { path, line } => write!(f, "{}:{}", path.display(), line),
+            Origin::Env { var } => write!(f, "environment variable {}", var),
+            Origin::Flag { flag } => write!(f, "flag {}", flag),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    // 환경 변수나 커맨드라인처럼 타입 없이 문자열로 들어온 값. 꺼낼 때 원하는 타입으로 파싱한다.
+    Text(String),
+    // 배열, 날짜처럼 여기서 다루지 않는 TOML 값. 타입 이름만 남겨둔다.
+    Other(&'static str),
+}
+
+impl Value {
+    // 에러 메시지에 쓰는 설명. 예: an integer (42)
+    pub fn describe(&self) -> String {
+        match self {
+            Value::String(s) => format!("a string ({:?})", s),
+            Value::Integer(i) => format!("an integer ({})", i),
+            Value::Float(x) => format!("a float ({})", x),
+            Value::Boolean(b) => format!("a boolean ({})", b),
+            Value::Text(s) => format!("{:?}", s),
+            Value::Other(name) => format!("a TOML {}", name),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    // 설정 파일을 읽지 못했다.
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    // TOML 문법이 틀렸다.
+    Syntax {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    // 값의 타입이 맞지 않는다.
+    WrongType {
+        key: String,
+        expected: String,
+        found: String,
+        origin: Origin,
+    },
+    // 타입은 맞지만 쓸 수 없는 값이다. (범위를 벗어났거나, 모르는 이름이거나)
+    // 설정에 없는 키에 대한 에러라면 위치를 알 수 없으므로 origin이 None이다.
+    InvalidValue {
+        key: String,
+        message: String,
+        origin: Option<Origin>,
+    },
+    // 모르는 키. 오타일 가능성이 높으므로 조용히 무시하지 않는다.
+    UnknownKey {
+        key: String,
+        origin: Origin,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // 원인인 io::Error는 source()로 따로 보여준다.
+            ConfigError::Io { path, .. } => {
+                write!(f, "failed to read config file {}", path.display())
+            }
+            ConfigError::Syntax {
+                path,
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{}:{}: {}", path.display(), line, column, message),
+            ConfigError::WrongType {
+                key,
+                expected,
+                found,
+                origin,
+            } => write!(
+                f,
+                "{}: {} must be {}, found {}",
+                origin, key, expected, found
+            ),
+            ConfigError::InvalidValue {
+                key,
+                message,
+                origin: Some(origin),
+            } => write!(f, "{}: invalid {}: {}", origin, key, message),
+            ConfigError::InvalidValue {
+                key,
+                message,
+                origin: None,
+            } => write!(f, "invalid {}: {}", key, message),
+            ConfigError::UnknownKey { key, origin } => write!(f, "{}: unknown key {}", origin, key),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// 설정 값에서 꺼낼 수 있는 타입
+pub trait FromValue: Sized {
+    // 에러 메시지에 쓰는 타입 설명. 예: an integer between 0 and 65535
+    fn expected() -> String;
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+impl FromValue for String {
+    fn expected() -> String {
+        "a string".to_string()
+    }
+
+    fn from_value(value: &Value) -> Option<String> {
+        match value {
+            Value::String(s) | Value::Text(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for PathBuf {
+    fn expected() -> String {
+        "a path".to_string()
+    }
+
+    fn from_value(value: &Value) -> Option<PathBuf> {
+        String::from_value(value).map(PathBuf::from)
+    }
+}
+
+impl FromValue for bool {
+    fn expected() -> String {
+        "a boolean".to_string()
+    }
+
+    fn from_value(value: &Value) -> Option<bool> {
+        match value {
+            Value::Boolean(b) => Some(*b),
+            Value::Text(s) => match s.trim() {
+                "true" | "1" => Some(true),
+                "false" | "0" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for f64 {
+    fn expected() -> String {
+        "a number".to_string()
+    }
+
+    fn from_value(value: &Value) -> Option<f64> {
+        match value {
+            Value::Float(x) => Some(*x),
+            Value::Integer(i) => Some(*i as f64),
+            Value::Text(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+// 정수 타입마다 범위를 확인해서 바꾼다. 범위를 벗어나면 타입이 맞지 않는 것으로 본다.
+macro_rules! impl_from_value_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl FromValue for $t {
+                fn expected() -> String {
+                    format!("an integer between {} and {}", <$t>::MIN, <$t>::MAX)
+                }
+
+                fn from_value(value: &Value) -> Option<$t> {
+                    match value {
+                        Value::Integer(i) => <$t>::try_from(*i).ok(),
+                        Value::Text(s) => s.trim().parse().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_value_for_integer!(i32, i64, u16, u32, u64, usize);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    // "section.key" -> 값과 그 값이 온 곳
+    entries: BTreeMap<String, (Value, Origin)>,
+}
+
+impl Config {
+    pub fn new() -> Config {
+        Config::default()
+    }
+
+    // TOML 파일을 읽는다. [section] 안의 key는 "section.key"가 된다.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Config, ConfigError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Config::parse(path, &text)
+    }
+
+    // load와 같지만 파일이 없으면 빈 설정을 돌려준다. 설정 파일을 꼭 만들지 않아도 되게 할 때 사용한다.
+    pub fn load_if_exists<P: AsRef<Path>>(path: P) -> Result<Config, ConfigError> {
+        match Config::load(path) {
+            Err(ConfigError::Io { source, .. }) if source.kind() == ErrorKind::NotFound => {
+                Ok(Config::new())
+            }
+            result => result,
+        }
+    }
+
+    // path는 에러 메시지에 쓰인다.
+    pub fn parse<P: AsRef<Path>>(path: P, text: &str) -> Result<Config, ConfigError> {
+        let path = path.as_ref();
+        let document = ImDocument::parse(text).map_err(|e| {
+            let (line, column) = position(text, e.span().map_or(0, |span| span.start));
+            ConfigError::Syntax {
+                path: path.to_path_buf(),
+                line,
+                column,
+                message: e.message().trim().to_string(),
+            }
+        })?;
+
+        let mut config = Config::new();
+        for (key, item) in document.iter() {
+            config.add_item(path, text, key.to_string(), item);
+        }
+        Ok(config)
+    }
+
+    fn add_item(&mut self, path: &Path, text: &str, key: String, item: &Item) {
+        let origin = |span: Option<Range<usize>>| Origin::File {
+            path: path.to_path_buf(),
+            line: position(text, span.map_or(0, |span| span.start)).0,
+        };
+        match item {
+            Item::Table(table) => {
+                for (name, item) in table.iter() {
+                    self.add_item(path, text, format!("{}.{}", key, name), item);
+                }
+            }
+            Item::Value(toml_edit::Value::InlineTable(table)) => {
+                for (name, value) in table.iter() {
+                    let item = Item::Value(value.clone());
+                    self.add_item(path, text, format!("{}.{}", key, name), &item);
+                }
+            }
+            Item::Value(value) => {
+                let converted = match value {
+                    toml_edit::Value::String(s) => Value::String(s.value().clone()),
+                    toml_edit::Value::Integer(i) => Value::Integer(*i.value()),
+                    toml_edit::Value::Float(x) => Value::Float(*x.value()),
+                    toml_edit::Value::Boolean(b) => Value::Boolean(*b.value()),
+                    other => Value::Other(other.type_name()),
+                };
+                self.entries.insert(key, (converted, origin(value.span())));
+            }
+            other => {
+                self.entries
+                    .insert(key, (Value::Other(other.type_name()), origin(other.span())));
+            }
+        }
+    }
+
+    // PREFIX_로 시작하는 환경 변수를 읽는다.
+    // GUESSING_GAME_MAX -> "max", GUESSING_GAME_SERVER__PORT -> "server.port" (섹션은 밑줄 두 개)
+    pub fn from_env<I>(prefix: &str, vars: I) -> Config
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let prefix = format!("{}_", prefix);
+        let mut config = Config::new();
+        for (var, value) in vars {
+            if let Some(name) = var.strip_prefix(&prefix) {
+                let key = name.to_lowercase().replace("__", ".");
+                config
+                    .entries
+                    .insert(key, (Value::Text(value), Origin::Env { var }));
+            }
+        }
+        config
+    }
+
+    pub fn set<K: Into<String>>(&mut self, key: K, value: Value, origin: Origin) {
+        self.entries.insert(key.into(), (value, origin));
+    }
+
+    // 키를 지우고 그 값을 돌려준다. 위의 층에서 아래 층의 값을 무시하게 할 때 사용한다.
+    pub fn remove(&mut self, key: &str) -> Option<(Value, Origin)> {
+        self.entries.remove(key)
+    }
+
+    // other를 위에 덮어쓴다. 같은 키가 있으면 other의 값이 남는다.
+    pub fn merge(mut self, other: Config) -> Config {
+        self.entries.extend(other.entries);
+        self
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(|key| key.as_str())
+    }
+
+    pub fn origin(&self, key: &str) -> Option<&Origin> {
+        self.entries.get(key).map(|(_, origin)| origin)
+    }
+
+    // 키가 없으면 Ok(None), 있는데 타입이 맞지 않으면 Err
+    pub fn get<T: FromValue>(&self, key: &str) -> Result<Option<T>, ConfigError> {
+        match self.entries.get(key) {
+            None => Ok(None),
+            Some((value, origin)) => match T::from_value(value) {
+                Some(value) => Ok(Some(value)),
+                None => Err(ConfigError::WrongType {
+                    key: key.to_string(),
+                    expected: T::expected(),
+                    found: value.describe(),
+                    origin: origin.clone(),
+                }),
+            },
+        }
+    }
+
+    // 문자열로 꺼낸 다음 FromStr 등으로 한 번 더 바꾼다. 바꾸다 난 에러는 InvalidValue가 된다.
+    // 예: config.get_with("difficulty", |s: String| s.parse::<Difficulty>())
+    pub fn get_with<T, U, E, F>(&self, key: &str, convert: F) -> Result<Option<U>, ConfigError>
+    where
+        T: FromValue,
+        E: fmt::Display,
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        match self.get::<T>(key)? {
+            None => Ok(None),
+            Some(value) => convert(value).map(Some).map_err(|e| self.invalid(key, e)),
+        }
+    }
+
+    // 여러 값을 함께 봐야 알 수 있는 에러(min >= max 등)를 key의 위치와 함께 알린다.
+    // key가 설정에 없으면 위치 없이 알린다.
+    pub fn invalid<M: fmt::Display>(&self, key: &str, message: M) -> ConfigError {
+        ConfigError::InvalidValue {
+            key: key.to_string(),
+            message: message.to_string(),
+            origin: self.origin(key).cloned(),
+        }
+    }
+
+    // known에 없는 키가 있으면 에러
+    pub fn check_keys(&self, known: &[&str]) -> Result<(), ConfigError> {
+        match self
+            .entries
+            .iter()
+            .find(|(key, _)| !known.contains(&key.as_str()))
+        {
+            Some((key, (_, origin))) => Err(ConfigError::UnknownKey {
+                key: key.clone(),
+                origin: origin.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+// 바이트 위치 -> (줄, 칸). 둘 다 1부터 센다.
+fn position(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |s| s.chars().count()) + 1;
+    (line, column)
+}
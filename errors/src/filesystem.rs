@@ -0,0 +1,351 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// 파일을 다루는 동작을 trait으로 모아둔다.
+// 실제 디스크를 쓰는 RealFileSystem 대신 MemoryFileSystem을 넘기면,
+// 파일이 없거나(NotFound) 권한이 없는(PermissionDenied) 경우를 디스크 없이 만들어서 확인할 수 있다.
+pub trait FileSystem {
+    // open, create로 얻는 열린 파일
+    type File: Read + Write;
+
+    // 이미 있는 파일을 읽고 쓸 수 있게 연다.
+    fn open(&self, path: &Path) -> io::Result<Self::File>;
+    // 파일을 만든다. 이미 있으면 내용을 비운다.
+    fn create(&self, path: &Path) -> io::Result<Self::File>;
+    // 파일을 새로 만든다. 이미 있으면 AlreadyExists로 실패한다.
+    fn create_new(&self, path: &Path) -> io::Result<Self::File>;
+    // 파일 내용을 모두 읽는다.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+    // 쓴 내용이 실제로 저장될 때까지 기다린다.
+    fn sync(&self, file: &mut Self::File) -> io::Result<()>;
+    // from의 권한을 to에도 똑같이 준다.
+    fn copy_permissions(&self, from: &Path, to: &Path) -> io::Result<()>;
+}
+
+// std::fs를 그대로 사용한다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealFileSystem;
+
+impl FileSystem for RealFileSystem {
+    type File = File;
+
+    fn open(&self, path: &Path) -> io::Result<File> {
+        OpenOptions::new().read(true).write(true).open(path)
+    }
+
+    fn create(&self, path: &Path) -> io::Result<File> {
+        File::create(path)
+    }
+
+    fn create_new(&self, path: &Path) -> io::Result<File> {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(path)
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        fs::rename(from, to)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        fs::remove_file(path)
+    }
+
+    fn sync(&self, file: &mut File) -> io::Result<()> {
+        file.sync_all()
+    }
+
+    fn copy_permissions(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let permissions = fs::metadata(from)?.permissions();
+        fs::set_permissions(to, permissions)
+    }
+}
+
+// MemoryFileSystem의 경로에 심어두는 고장
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fault {
+    // 항상 kind로 실패한다. (NotFound, PermissionDenied 등)
+    Fail(ErrorKind),
+    // 처음 n번만 kind로 실패하고, 그 다음부터는 성공한다. (Interrupted처럼 잠깐 일어나는 에러)
+    FailTimes(ErrorKind, usize),
+    // 읽으면 내용 뒤에 UTF-8이 아닌 바이트가 붙어서 나온다.
+    InvalidUtf8,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    files: BTreeMap<PathBuf, Vec<u8>>,
+    dirs: BTreeSet<PathBuf>,
+    faults: BTreeMap<PathBuf, Fault>,
+}
+
+impl State {
+    // path나 그 상위 디렉터리에 심어둔 고장이 있으면 그 에러로 실패한다.
+    fn check(&mut self, path: &Path) -> io::Result<()> {
+        for ancestor in path.ancestors() {
+            match self.faults.get_mut(ancestor) {
+                Some(Fault::Fail(kind)) => return Err(io::Error::from(*kind)),
+                Some(Fault::FailTimes(kind, times)) if *times > 0 => {
+                    *times -= 1;
+                    return Err(io::Error::from(*kind));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    // "hello.txt"의 parent는 빈 경로다. 현재 디렉터리와 "/"는 항상 있다고 본다.
+    fn is_dir(&self, path: &Path) -> bool {
+        path.as_os_str().is_empty() || path.parent().is_none() || self.dirs.contains(path)
+    }
+
+    // path까지 가는 길에 파일이 있으면 NotADirectory, 디렉터리가 없으면 NotFound
+    fn check_parents(&self, path: &Path) -> io::Result<()> {
+        for ancestor in path.ancestors().skip(1) {
+            if self.files.contains_key(ancestor) {
+                return Err(io::Error::from(ErrorKind::NotADirectory));
+            }
+        }
+        match path.parent() {
+            Some(parent) if !self.is_dir(parent) => Err(io::Error::from(ErrorKind::NotFound)),
+            _ => Ok(()),
+        }
+    }
+
+    // path에 새 파일을 둘 수 있는지 확인한다.
+    fn check_new_file(&self, path: &Path) -> io::Result<()> {
+        if self.is_dir(path) {
+            return Err(io::Error::from(ErrorKind::IsADirectory));
+        }
+        self.check_parents(path)
+    }
+
+    fn contents(&self, path: &Path) -> io::Result<Vec<u8>> {
+        if self.is_dir(path) {
+            return Err(io::Error::from(ErrorKind::IsADirectory));
+        }
+        self.check_parents(path)?;
+        let mut contents = self
+            .files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| io::Error::from(ErrorKind::NotFound))?;
+        if self.faults.get(path) == Some(&Fault::InvalidUtf8) {
+            contents.push(0xff);
+        }
+        Ok(contents)
+    }
+}
+
+// 메모리 안에만 있는 파일 시스템. 테스트에서 사용한다.
+// clone하면 같은 파일들을 함께 보므로, 넘겨준 뒤에도 내용을 확인할 수 있다.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileSystem {
+    state: Arc<Mutex<State>>,
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> MemoryFileSystem {
+        MemoryFileSystem::default()
+    }
+
+    // 상위 디렉터리까지 만들어서 파일을 넣어둔다.
+    pub fn with_file<P: Into<PathBuf>>(self, path: P, contents: &[u8]) -> MemoryFileSystem {
+        let path = path.into();
+        {
+            let mut state = lock(&self.state);
+            if let Some(parent) = path.parent() {
+                add_dirs(&mut state, parent);
+            }
+            state.files.insert(path, contents.to_vec());
+        }
+        self
+    }
+
+    pub fn with_dir<P: Into<PathBuf>>(self, path: P) -> MemoryFileSystem {
+        add_dirs(&mut lock(&self.state), &path.into());
+        self
+    }
+
+    // path와 그 아래의 모든 경로에 대한 동작이 fault대로 실패한다.
+    pub fn with_fault<P: Into<PathBuf>>(self, path: P, fault: Fault) -> MemoryFileSystem {
+        lock(&self.state).faults.insert(path.into(), fault);
+        self
+    }
+
+    // 저장된 내용. 심어둔 고장과 상관없이 그대로 돌려준다.
+    pub fn contents<P: AsRef<Path>>(&self, path: P) -> Option<Vec<u8>> {
+        lock(&self.state).files.get(path.as_ref()).cloned()
+    }
+
+    // 모든 파일의 경로 (정렬된 순서)
+    pub fn files(&self) -> Vec<PathBuf> {
+        lock(&self.state).files.keys().cloned().collect()
+    }
+
+    fn file(&self, path: &Path) -> MemoryFile {
+        MemoryFile {
+            state: Arc::clone(&self.state),
+            path: path.to_path_buf(),
+            position: 0,
+        }
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    type File = MemoryFile;
+
+    fn open(&self, path: &Path) -> io::Result<MemoryFile> {
+        let mut state = lock(&self.state);
+        state.check(path)?;
+        state.contents(path)?;
+        Ok(self.file(path))
+    }
+
+    fn create(&self, path: &Path) -> io::Result<MemoryFile> {
+        let mut state = lock(&self.state);
+        state.check(path)?;
+        state.check_new_file(path)?;
+        state.files.insert(path.to_path_buf(), Vec::new());
+        Ok(self.file(path))
+    }
+
+    fn create_new(&self, path: &Path) -> io::Result<MemoryFile> {
+        let mut state = lock(&self.state);
+        state.check(path)?;
+        if state.files.contains_key(path) {
+            return Err(io::Error::from(ErrorKind::AlreadyExists));
+        }
+        state.check_new_file(path)?;
+        state.files.insert(path.to_path_buf(), Vec::new());
+        Ok(self.file(path))
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let mut state = lock(&self.state);
+        state.check(path)?;
+        state.contents(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        state.check(path)?;
+        if state.files.contains_key(path) {
+            return Err(io::Error::from(ErrorKind::AlreadyExists));
+        }
+        if path
+            .ancestors()
+            .any(|ancestor| state.files.contains_key(ancestor))
+        {
+            return Err(io::Error::from(ErrorKind::NotADirectory));
+        }
+        add_dirs(&mut state, path);
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        state.check(from)?;
+        state.check(to)?;
+        state.contents(from)?;
+        state.check_new_file(to)?;
+        let contents = state.files.remove(from).unwrap_or_default();
+        state.files.insert(to.to_path_buf(), contents);
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        state.check(path)?;
+        state.contents(path)?;
+        state.files.remove(path);
+        Ok(())
+    }
+
+    fn sync(&self, _file: &mut MemoryFile) -> io::Result<()> {
+        Ok(())
+    }
+
+    // 메모리 안의 파일에는 권한이 없으므로 둘 다 있는지만 확인한다.
+    fn copy_permissions(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        state.check(from)?;
+        state.check(to)?;
+        state.contents(from)?;
+        state.contents(to)?;
+        Ok(())
+    }
+}
+
+// MemoryFileSystem에서 연 파일.
+// 경로로 내용을 찾으므로, 실제 파일과 달리 rename이나 remove_file 뒤에는 읽고 쓸 수 없다.
+#[derive(Debug)]
+pub struct MemoryFile {
+    state: Arc<Mutex<State>>,
+    path: PathBuf,
+    position: usize,
+}
+
+impl Read for MemoryFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let contents = lock(&self.state).contents(&self.path)?;
+        let rest = contents.get(self.position..).unwrap_or(&[]);
+        let n = rest.len().min(buf.len());
+        buf[..n].copy_from_slice(&rest[..n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+impl Write for MemoryFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = lock(&self.state);
+        let contents = state
+            .files
+            .get_mut(&self.path)
+            .ok_or_else(|| io::Error::from(ErrorKind::NotFound))?;
+        let end = self.position + buf.len();
+        if contents.len() < end {
+            contents.resize(end, 0);
+        }
+        contents[self.position..end].copy_from_slice(buf);
+        self.position = end;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// 다른 곳에서 lock을 잡은 채 panic했더라도 내용은 그대로 쓴다.
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn add_dirs(state: &mut State, path: &Path) {
+    for ancestor in path.ancestors() {
+        if !ancestor.as_os_str().is_empty() {
+            state.dirs.insert(ancestor.to_path_buf());
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::filesystem::{FileSystem, RealFileSystem};
+
+// "파일을 열어보고, 없으면(ErrorKind::NotFound) 만든다"는 main.rs의 예제를 다시 쓸 수 있게 모아둔 함수들.
+// 예제에서는 실패하면 panic했지만, 여기서는 어떤 파일에 무엇을 하다가 실패했는지를 담은 FsError를 돌려준다.
+//
+// 권한이 없는 경우(PermissionDenied)는 없는 것과 다르게 취급한다.
+// 읽을 수 없는 파일을 새로 만들어서 덮어쓰면 안 되므로, 만들지 않고 그대로 에러를 돌려준다.
+//
+// _in으로 끝나는 함수는 FileSystem을 받아서 그 위에서 동작하고, 나머지는 실제 디스크(RealFileSystem)를 사용한다.
+
+// 실패한 작업
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operation {
+    Open,
+    Create,
+    CreateDir,
+    Write,
+    Rename,
+    SetPermissions,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Operation::Open => "open",
+            Operation::Create => "create",
+            Operation::CreateDir => "create directory",
+            Operation::Write => "write",
+            Operation::Rename => "rename into",
+            Operation::SetPermissions => "set permissions of",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug)]
+pub struct FsError {
+    operation: Operation,
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl FsError {
+    fn new(operation: Operation, path: &Path, source: io::Error) -> FsError {
+        FsError {
+            operation,
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.source.kind()
+    }
+
+    pub fn is_permission_denied(&self) -> bool {
+        self.kind() == ErrorKind::PermissionDenied
+    }
+}
+
+impl fmt::Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "failed to {} {}: {}",
+            self.operation,
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl Error for FsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+// 읽고 쓸 수 있게 연다. 파일이 없으면 (필요하면 상위 디렉터리까지) 빈 파일을 만든다.
+pub fn open_or_create<P: AsRef<Path>>(path: P) -> Result<File, FsError> {
+    open_or_create_in(&RealFileSystem, path)
+}
+
+pub fn open_or_create_in<F: FileSystem, P: AsRef<Path>>(
+    fs: &F,
+    path: P,
+) -> Result<F::File, FsError> {
+    let path = path.as_ref();
+    match fs.open(path) {
+        Ok(file) => Ok(file),
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            create_parent_dirs_in(fs, path)?;
+            match fs.create_new(path) {
+                Ok(file) => Ok(file),
+                // 그 사이에 다른 누군가가 만들었으면 그 파일을 연다.
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => fs
+                    .open(path)
+                    .map_err(|e| FsError::new(Operation::Open, path, e)),
+                Err(e) => Err(FsError::new(Operation::Create, path, e)),
+            }
+        }
+        Err(error) => Err(FsError::new(Operation::Open, path, error)),
+    }
+}
+
+// open_or_create와 같지만, 파일이 없으면 default_contents를 써넣은 파일을 만든다.
+// 내용은 write_atomic으로 쓰므로 다른 쪽에서 반쯤 쓰인 파일을 보는 일은 없다.
+pub fn open_or_init<P: AsRef<Path>>(path: P, default_contents: &[u8]) -> Result<File, FsError> {
+    open_or_init_in(&RealFileSystem, path, default_contents)
+}
+
+pub fn open_or_init_in<F: FileSystem, P: AsRef<Path>>(
+    fs: &F,
+    path: P,
+    default_contents: &[u8],
+) -> Result<F::File, FsError> {
+    let path = path.as_ref();
+    match fs.open(path) {
+        Ok(file) => Ok(file),
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            write_atomic_in(fs, path, default_contents)?;
+            fs.open(path)
+                .map_err(|e| FsError::new(Operation::Open, path, e))
+        }
+        Err(error) => Err(FsError::new(Operation::Open, path, error)),
+    }
+}
+
+// path가 들어갈 디렉터리들을 만든다. 이미 있으면 아무것도 하지 않는다.
+pub fn create_parent_dirs<P: AsRef<Path>>(path: P) -> Result<(), FsError> {
+    create_parent_dirs_in(&RealFileSystem, path)
+}
+
+pub fn create_parent_dirs_in<F: FileSystem, P: AsRef<Path>>(
+    fs: &F,
+    path: P,
+) -> Result<(), FsError> {
+    match path.as_ref().parent() {
+        // "hello.txt"처럼 디렉터리 없이 이름만 있으면 parent는 빈 경로다.
+        Some(parent) if !parent.as_os_str().is_empty() => fs
+            .create_dir_all(parent)
+            .map_err(|e| FsError::new(Operation::CreateDir, parent, e)),
+        _ => Ok(()),
+    }
+}
+
+// 같은 디렉터리의 임시 파일에 다 쓴 다음 rename으로 바꿔치기한다.
+// rename은 한 번에 일어나므로, 중간에 실패하거나 프로그램이 죽어도 path에는 예전 내용이나 새 내용만 남는다.
+// 이미 있던 파일을 덮어쓰면 그 파일의 권한을 그대로 유지한다.
+pub fn write_atomic<P: AsRef<Path>>(path: P, contents: &[u8]) -> Result<(), FsError> {
+    write_atomic_in(&RealFileSystem, path, contents)
+}
+
+pub fn write_atomic_in<F: FileSystem, P: AsRef<Path>>(
+    fs: &F,
+    path: P,
+    contents: &[u8],
+) -> Result<(), FsError> {
+    let path = path.as_ref();
+    create_parent_dirs_in(fs, path)?;
+    let temp = temp_path(path);
+    let result = write_temp(fs, path, &temp, contents);
+    if result.is_err() {
+        // 실패했으면 임시 파일을 치운다. 치우다 난 에러보다 원래 에러가 중요하므로 무시한다.
+        let _ = fs.remove_file(&temp);
+    }
+    result
+}
+
+fn write_temp<F: FileSystem>(
+    fs: &F,
+    path: &Path,
+    temp: &Path,
+    contents: &[u8],
+) -> Result<(), FsError> {
+    let mut file = fs
+        .create(temp)
+        .map_err(|e| FsError::new(Operation::Create, temp, e))?;
+    file.write_all(contents)
+        .and_then(|()| fs.sync(&mut file))
+        .map_err(|e| FsError::new(Operation::Write, temp, e))?;
+    match fs.copy_permissions(path, temp) {
+        Ok(()) => {}
+        // 새로 만드는 파일이면 유지할 권한이 없다.
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => return Err(FsError::new(Operation::SetPermissions, temp, e)),
+    }
+    fs.rename(temp, path)
+        .map_err(|e| FsError::new(Operation::Rename, path, e))
+}
+
+// 권한을 바꾼다. (예: 0o600이면 나만 읽고 쓸 수 있다)
+#[cfg(unix)]
+pub fn set_mode<P: AsRef<Path>>(path: P, mode: u32) -> Result<(), FsError> {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = path.as_ref();
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+        .map_err(|e| FsError::new(Operation::SetPermissions, path, e))
+}
+
+// 같은 프로세스 안에서도 겹치지 않도록 번호를 붙인다.
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let count = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(".{}.{}.{}.tmp", name, process::id(), count))
+}
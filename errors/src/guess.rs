@@ -1,17 +1,10 @@
-pub struct Guess {
-  value: i32,
-}
-impl Guess {
-  pub fn new(value: i32) -> Guess {
-    if value < 1 || value > 100 {
-      panic!("Guess value must be between 1 and 100, got {}.", value);
-    }
-    Guess { value }
-  }
-  // getter와 같은 역할을 한다.
-  // 이는 Guess 구조체의 value가 private하기 때문인데, private한 이유는
-  // new를 이용하지 않고 직접적으로 값을 넣어주는 것을 방지하기 위해서이다.
-  pub fn value(&self) -> i32 {
-    self.value
-  }
-}
+use crate::bounded::{Bounded, BoundedError};
+
+// 1부터 100까지의 값만 담을 수 있는 추측 값.
+// 처음에는 struct를 직접 만들고 new에서 1..=100인지 검사했는데,
+// 같은 검사를 다른 범위와 타입에도 쓸 수 있도록 Bounded로 옮겼다. (bounded.rs 참고)
+pub type Guess = Bounded<i32, 1, 100>;
+
+// Guess::try_new와 "42".parse::<Guess>()가 돌려주는 에러.
+// TooLow { min, got }, TooHigh { max, got }, Invalid(ParseIntError) 중 하나다.
+pub type GuessError = BoundedError<i32>;
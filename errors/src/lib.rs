@@ -0,0 +1,10 @@
+// main.rs의 예제에서 사용하는 타입들.
+// 라이브러리로 분리해두면 tests/ 폴더의 통합 테스트에서도 불러다 쓸 수 있다.
+pub mod bounded;
+pub mod config;
+pub mod filesystem;
+pub mod fsutil;
+pub mod guess;
+pub mod report;
+pub mod retry;
+pub mod username;
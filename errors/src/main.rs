@@ -1,5 +1,10 @@
-mod guess;
-use guess::Guess;
+// 설명을 위한 예제라서 만들기만 하고 쓰지 않는 값이 많다.
+#![allow(unused_variables, clippy::useless_vec)]
+
+use errors::fsutil;
+use errors::guess::Guess;
+use errors::report::{self, Format, Report};
+use errors::username::load_username;
 
 fn main() {
     // panic!("crash and burn"); panic! 매크로를 통해 에러를 발생시킬 수 있다. 이를 통해 에러 발생된곳을 추적할 수 있다.
@@ -19,27 +24,18 @@ fn main() {
                                      //     Err(error) => panic!("Problem opening the file: {:?}", error),
                                      // };
 
-    // 에러가 났을 때, 해당 파일이 존재하지 않는 경우 새로 만들어주는 동작
-    use std::io::ErrorKind;
-    let f = match f {
+    // 에러가 났을 때, 해당 파일이 존재하지 않는 경우 새로 만들어주는 동작.
+    // error.kind()가 ErrorKind::NotFound인지 match로 확인해서 File::create를 부르면 되는데,
+    // 자주 쓰는 동작이라 fsutil::open_or_create로 만들어두었다. (fsutil.rs 참고)
+    // panic하지 않고, 어떤 파일에서 무엇이 실패했는지를 담은 Result를 돌려준다.
+    // 에러는 report로 원인까지 출력하고, 에러 종류에 맞는 exit code로 끝낸다. (report.rs 참고)
+    let f = match fsutil::open_or_create("hello.txt") {
         Ok(file) => file,
-        Err(error) => match error.kind() {
-            ErrorKind::NotFound => match File::create("hello.txt") {
-                Ok(fc) => fc,
-                Err(e) => panic!("Problem creating the file: {:?}", e),
-            },
-            other_error => panic!("Problem opening the file: {:?}", other_error),
-        },
+        Err(e) => report::exit("errors", Format::Text, &Report::new(&e)),
     };
     // match 대신 closure를 사용한 방식
-    let f = File::open("hello.txt").unwrap_or_else(|error| {
-        if error.kind() == ErrorKind::NotFound {
-            File::create("hello.txt").unwrap_or_else(|error| {
-                panic!("Problem creating the file: {:?}", error);
-            })
-        } else {
-            panic!("Problem opening the file: {:?}", error);
-        }
+    let f = fsutil::open_or_create("hello.txt").unwrap_or_else(|e| {
+        panic!("Problem opening the file: {}", e);
     });
 
     // unwrap을 사용하면, 위에서 match로 해준것과 똑같은 동작을 해준다. Result가 Ok면 그대로 반환해주고, 아니면 panic!을 호출한다.
@@ -51,51 +47,22 @@ fn main() {
     // 이는 panic을 만들기 전에 타입 체킹에서 잘못된 값이 들어오는 것을 막아줄 수 있다.
     // guess 파일을 참고하자.
     let guess = Guess::new(10);
-    let myVal = guess.value();
-    println!("my Val: {}", myVal);
-}
-
-use std::fs::{read_to_string, File};
-use std::io::{self, Error, Read};
-
-// error propagating 하는 함수.
-// 에러를 함수내에서 처리하는 것보다 반환해주는게 나은 경우가 있음.
-// 함수를 호출하는쪽에서 에러 제어가 가능
-fn read_username_from_file() -> Result<String, Error> {
-    let f = File::open("hello.txt");
-
-    let mut f = match f {
-        Ok(file) => file,
-        Err(e) => return Err(e),
-    };
+    let my_val = guess.value();
+    println!("my Val: {}", my_val);
 
-    let mut s = String::new();
-
-    match f.read_to_string(&mut s) {
-        Ok(_) => Ok(s),
-        Err(e) => Err(e),
+    // 범위를 벗어날 수 있는 값은 panic하는 new 대신 try_new나 parse로 받아서 Result로 처리한다.
+    match "150".parse::<Guess>() {
+        Ok(guess) => println!("guess: {}", guess),
+        Err(e) => println!("invalid guess: {}", e),
     }
-}
-
-// 이러한 패턴은 꽤 자주 요구되므로, ? 연산자를 통해 쉽게 만들 수 있다.
-// ?와 위 match의 차이점은, ?로 부터 반환되는 Error 타입은 함수의 Return 타입으로 변환된다.
-// 그렇기 때문에 ?를 사용하려면 함수의 반환 타입이 Result, Option 또는 std::ops::Try이 구현된 타입이어야 한다.
-fn read_username_from_file_2() -> Result<String, Error> {
-    let mut f = File::open("hello.txt")?; // ? 연산자를 사용한다.
-                                          // 성공하면 값이 저장되고, 실패하면 Err를 함수에서 반환해준다.
-    let mut s = String::new();
-    f.read_to_string(&mut s)?; // 위와 마찬가지
-    Ok(s)
-}
 
-// ?는 chaining도 가능하다.
-fn read_username_from_file3() -> Result<String, Error> {
-    let mut s = String::new();
-    File::open("hello.txt")?.read_to_string(&mut s)?;
-    Ok(s)
-}
-
-// 이렇게 파일에서 문자열을 읽어오는건 자주사용되는 작업이므로, 더 짧은 방법도 제공된다.
-fn read_username_from_file4() -> Result<String, io::Error> {
-    read_to_string("hello.txt")
+    // error propagating: 에러를 함수 안에서 처리하는 것보다 반환해주는게 나은 경우가 있다.
+    // load_username은 실패한 이유를 UsernameError로 돌려주고, 어떻게 처리할지는 호출하는 쪽에서 정한다.
+    // 다만 Interrupted처럼 잠깐 일어나는 에러는 조금씩 더 기다리며 몇 번 다시 읽어본다. (retry.rs 참고)
+    // 파일은 FileSystem trait을 거쳐 읽으므로, 테스트에서는 load_username_in에 MemoryFileSystem을 넘겨서
+    // 파일이 없거나 권한이 없는 경우를 디스크 없이 만들어볼 수 있다. (filesystem.rs, tests/filesystem.rs 참고)
+    match load_username("hello.txt") {
+        Ok(username) => println!("username: {}", username),
+        Err(e) => println!("Failed to load username: {}", e),
+    }
 }
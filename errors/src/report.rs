@@ -0,0 +1,257 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, ErrorKind, Write};
+use std::process;
+
+use serde_json::{json, Value};
+
+use crate::bounded::BoundedError;
+use crate::config::ConfigError;
+use crate::username::UsernameError;
+
+// 프로그램이 실패했을 때 panic!, unwrap, expect 대신 사용하는 에러 보고.
+//
+//   guessing_game: Failed to read high scores
+//     caused by: No such file or directory (os error 2)
+//
+// - source()를 따라가며 원인이 된 에러들을 모두 보여준다.
+// - RUST_BACKTRACE=1 이면 backtrace도 보여준다. (panic!과 같은 환경 변수를 사용한다)
+// - 에러의 종류(Category)마다 다른 exit code로 끝나므로, 스크립트에서 이유를 구분할 수 있다.
+// - JSON으로도 출력할 수 있다.
+
+// exit code는 sysexits.h의 값을 따른다. 잘못된 인자만은 원래 쓰던 2를 그대로 쓴다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Category {
+    // 아래 어디에도 속하지 않는 실패
+    Failure,
+    // 잘못된 커맨드라인 인자
+    Usage,
+    // 읽은 데이터의 형식이 잘못되었다.
+    InvalidData,
+    NotFound,
+    // 네트워크 연결 실패 등
+    Unavailable,
+    // 그 밖의 입출력 에러
+    Io,
+    PermissionDenied,
+    // 설정 파일이나 환경 변수의 값이 잘못되었다.
+    Config,
+}
+
+impl Category {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Category::Failure => 1,
+            Category::Usage => 2,
+            Category::InvalidData => 65,
+            Category::NotFound => 66,
+            Category::Unavailable => 69,
+            Category::Io => 74,
+            Category::PermissionDenied => 77,
+            Category::Config => 78,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::Failure => "failure",
+            Category::Usage => "usage",
+            Category::InvalidData => "invalid_data",
+            Category::NotFound => "not_found",
+            Category::Unavailable => "unavailable",
+            Category::Io => "io",
+            Category::PermissionDenied => "permission_denied",
+            Category::Config => "config",
+        }
+    }
+
+    // 에러와 그 원인들을 차례로 보면서, 종류를 알 수 있는 첫 번째 에러로 정한다.
+    pub fn of(error: &(dyn Error + 'static)) -> Category {
+        chain(error)
+            .find_map(category_of_one)
+            .unwrap_or(Category::Failure)
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn category_of_one(error: &(dyn Error + 'static)) -> Option<Category> {
+    // dyn Error는 downcast_ref로 원래 타입인지 확인해볼 수 있다.
+    if let Some(error) = error.downcast_ref::<io::Error>() {
+        return Some(category_of_io(error.kind()));
+    }
+    if let Some(error) = error.downcast_ref::<UsernameError>() {
+        return match error {
+            UsernameError::NotFound { .. } => Some(Category::NotFound),
+            UsernameError::PermissionDenied { .. } => Some(Category::PermissionDenied),
+            // 원인인 io::Error를 보고 정한다.
+            UsernameError::Io { .. } => None,
+            _ => Some(Category::InvalidData),
+        };
+    }
+    if let Some(error) = error.downcast_ref::<ConfigError>() {
+        return match error {
+            // 설정 파일을 읽지 못한 것은 원인인 io::Error를 보고 정한다.
+            ConfigError::Io { .. } => None,
+            _ => Some(Category::Config),
+        };
+    }
+    if error.downcast_ref::<BoundedError<i32>>().is_some() {
+        return Some(Category::InvalidData);
+    }
+    None
+}
+
+fn category_of_io(kind: ErrorKind) -> Category {
+    match kind {
+        ErrorKind::NotFound => Category::NotFound,
+        ErrorKind::PermissionDenied => Category::PermissionDenied,
+        ErrorKind::InvalidData | ErrorKind::InvalidInput | ErrorKind::UnexpectedEof => {
+            Category::InvalidData
+        }
+        ErrorKind::ConnectionRefused
+        | ErrorKind::ConnectionReset
+        | ErrorKind::ConnectionAborted
+        | ErrorKind::NotConnected
+        | ErrorKind::AddrInUse
+        | ErrorKind::AddrNotAvailable
+        | ErrorKind::TimedOut => Category::Unavailable,
+        _ => Category::Io,
+    }
+}
+
+// 에러와 source()로 이어지는 원인들
+pub fn chain<'a>(
+    error: &'a (dyn Error + 'static),
+) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    // successors는 앞의 값으로 다음 값을 만들어가다가 None이 나오면 멈춘다.
+    std::iter::successors(Some(error), |&error| error.source())
+}
+
+// 에러에 "무엇을 하다가" 실패했는지를 덧붙인다. 원래 에러는 source()로 남는다.
+#[derive(Debug)]
+pub struct ContextError {
+    message: String,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+// expect("Failed to read high scores") 대신 context("Failed to read high scores")?를 쓸 수 있게 한다.
+pub trait Context<T> {
+    fn context<M: Into<String>>(self, message: M) -> Result<T, ContextError>;
+}
+
+impl<T, E: Error + Send + Sync + 'static> Context<T> for Result<T, E> {
+    fn context<M: Into<String>>(self, message: M) -> Result<T, ContextError> {
+        self.map_err(|error| ContextError {
+            message: message.into(),
+            source: Box::new(error),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+// 출력하기 위해 에러에서 뽑아둔 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub category: Category,
+    pub message: String,
+    // 원인들의 메시지. 가까운 원인부터.
+    pub causes: Vec<String>,
+    pub backtrace: Option<String>,
+}
+
+impl Report {
+    // backtrace는 RUST_BACKTRACE(또는 RUST_LIB_BACKTRACE)가 켜져 있을 때만 남긴다.
+    pub fn new(error: &(dyn Error + 'static)) -> Report {
+        let backtrace = Backtrace::capture();
+        let backtrace = match backtrace.status() {
+            BacktraceStatus::Captured => Some(backtrace.to_string()),
+            _ => None,
+        };
+        Report::without_backtrace(error).with_backtrace(backtrace)
+    }
+
+    pub fn without_backtrace(error: &(dyn Error + 'static)) -> Report {
+        let mut messages = chain(error).map(|error| error.to_string());
+        Report {
+            category: Category::of(error),
+            message: messages.next().unwrap_or_default(),
+            causes: messages.collect(),
+            backtrace: None,
+        }
+    }
+
+    // 에러만 보고는 알 수 없는 종류(예: 잘못된 인자)를 직접 정한다.
+    pub fn with_category(mut self, category: Category) -> Report {
+        self.category = category;
+        self
+    }
+
+    pub fn with_backtrace(mut self, backtrace: Option<String>) -> Report {
+        self.backtrace = backtrace;
+        self
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        self.category.exit_code()
+    }
+
+    pub fn write_text(&self, program: &str, output: &mut dyn Write) -> io::Result<()> {
+        writeln!(output, "{}: {}", program, self.message)?;
+        for cause in &self.causes {
+            writeln!(output, "  caused by: {}", cause)?;
+        }
+        if let Some(backtrace) = &self.backtrace {
+            writeln!(output, "\nbacktrace:\n{}", backtrace)?;
+        }
+        Ok(())
+    }
+
+    pub fn to_json(&self, program: &str) -> Value {
+        json!({
+            "program": program,
+            "error": self.message,
+            "category": self.category.name(),
+            "exit_code": self.exit_code(),
+            "causes": self.causes,
+            "backtrace": self.backtrace,
+        })
+    }
+
+    // 사람이 읽는 문장이나 한 줄짜리 JSON으로 출력한다.
+    pub fn write(&self, program: &str, format: Format, output: &mut dyn Write) -> io::Result<()> {
+        match format {
+            Format::Text => self.write_text(program, output),
+            Format::Json => writeln!(output, "{}", self.to_json(program)),
+        }
+    }
+}
+
+// 에러를 stderr에 보고하고 종류에 맞는 exit code로 프로그램을 끝낸다.
+pub fn exit(program: &str, format: Format, report: &Report) -> ! {
+    // stderr에 쓰는 것마저 실패하면 더 알릴 방법이 없으므로 무시하고 끝낸다.
+    let _ = report.write(program, format, &mut io::stderr());
+    process::exit(report.exit_code())
+}
@@ -0,0 +1,180 @@
+use std::io::{self, ErrorKind};
+use std::thread;
+use std::time::Duration;
+
+use crate::fsutil::FsError;
+use crate::username::UsernameError;
+
+// 잠깐 일어나는 입출력 에러는 조금 기다렸다가 다시 시도하면 성공할 수 있다.
+// 다시 시도할지는 io::ErrorKind로 정한다.
+//
+// - Interrupted: 시그널 때문에 도중에 멈췄다.
+// - WouldBlock: 지금은 준비가 안 됐다.
+// - TimedOut: 시간 안에 끝나지 않았다.
+//
+// NotFound나 PermissionDenied처럼 기다린다고 바뀌지 않는 에러는 바로 돌려준다.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Class {
+    // 다시 시도해볼 만하다.
+    Transient,
+    // 다시 해도 똑같이 실패한다.
+    Permanent,
+}
+
+pub fn classify(kind: ErrorKind) -> Class {
+    match kind {
+        ErrorKind::Interrupted | ErrorKind::WouldBlock | ErrorKind::TimedOut => Class::Transient,
+        // NotFound, PermissionDenied를 포함한 나머지는 모두 기다려도 바뀌지 않는다고 본다.
+        _ => Class::Permanent,
+    }
+}
+
+// 에러가 다시 시도할 만한지 알려준다. 원인인 io::Error의 종류를 보고 정한다.
+pub trait Transient {
+    fn is_transient(&self) -> bool;
+}
+
+impl Transient for io::Error {
+    fn is_transient(&self) -> bool {
+        classify(self.kind()) == Class::Transient
+    }
+}
+
+impl Transient for FsError {
+    fn is_transient(&self) -> bool {
+        classify(self.kind()) == Class::Transient
+    }
+}
+
+impl Transient for UsernameError {
+    fn is_transient(&self) -> bool {
+        match self {
+            UsernameError::Io { source, .. } => source.is_transient(),
+            // 파일이 없거나 내용이 잘못된 것은 다시 읽어도 같다.
+            _ => false,
+        }
+    }
+}
+
+// 다시 시도하기 전에 기다린다. 테스트에서는 RecordingClock으로 바꿔서 실제로 기다리지 않는다.
+pub trait Clock {
+    fn sleep(&mut self, duration: Duration);
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+// 기다리지 않고, 기다리라고 한 시간만 기록해둔다.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingClock {
+    sleeps: Vec<Duration>,
+}
+
+impl RecordingClock {
+    pub fn new() -> RecordingClock {
+        RecordingClock::default()
+    }
+
+    pub fn sleeps(&self) -> &[Duration] {
+        &self.sleeps
+    }
+
+    pub fn total(&self) -> Duration {
+        self.sleeps.iter().sum()
+    }
+}
+
+impl Clock for RecordingClock {
+    fn sleep(&mut self, duration: Duration) {
+        self.sleeps.push(duration);
+    }
+}
+
+// 몇 번까지, 얼마나 기다리며 다시 시도할지.
+// 기다리는 시간은 initial_delay부터 multiplier배씩 늘어나고, max_delay를 넘지 않는다.
+// (기본값: 10ms, 20ms, 40ms, 80ms를 기다리며 모두 5번 시도한다)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backoff {
+    max_attempts: u32,
+    initial_delay: Duration,
+    multiplier: u32,
+    max_delay: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Backoff {
+        Backoff {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(10),
+            multiplier: 2,
+            max_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Backoff {
+    pub fn new() -> Backoff {
+        Backoff::default()
+    }
+
+    // 처음 시도를 포함한 횟수. 1이면 다시 시도하지 않는다.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Backoff {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_initial_delay(mut self, delay: Duration) -> Backoff {
+        self.initial_delay = delay;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: u32) -> Backoff {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_max_delay(mut self, delay: Duration) -> Backoff {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    // retry번째(0부터) 다시 시도하기 전에 기다릴 시간
+    pub fn delay(&self, retry: u32) -> Duration {
+        // 곱하다가 넘치면 어차피 max_delay보다 크다.
+        self.multiplier
+            .checked_pow(retry)
+            .and_then(|factor| self.initial_delay.checked_mul(factor))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    // operation이 성공하거나, 다시 시도할 수 없는 에러가 나거나, 횟수를 다 쓸 때까지 반복한다.
+    // 실패하면 마지막 에러를 돌려준다.
+    pub fn retry<T, E, C, F>(&self, clock: &mut C, mut operation: F) -> Result<T, E>
+    where
+        E: Transient,
+        C: Clock + ?Sized,
+        F: FnMut() -> Result<T, E>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation() {
+                Err(error) if error.is_transient() && attempt < self.max_attempts => {
+                    clock.sleep(self.delay(attempt - 1));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
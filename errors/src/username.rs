@@ -0,0 +1,176 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::str::{self, Utf8Error};
+
+use crate::filesystem::{FileSystem, RealFileSystem};
+use crate::retry::{Backoff, Clock, SystemClock};
+
+// 파일에서 사용자 이름을 읽는다.
+// 처음에는 read_username_from_file을 match, ?, ? 체이닝, fs::read_to_string으로 네 번 만들어봤는데,
+// 모두 io::Error만 돌려줘서 어느 파일에서 무엇이 잘못됐는지 알 수 없었다.
+// 여기서는 하나로 합치고, 실패한 이유를 구분하는 에러 타입을 직접 만든다.
+
+// 사용자 이름의 최대 길이
+pub const MAX_LEN: usize = 32;
+
+#[derive(Debug)]
+pub enum UsernameError {
+    NotFound {
+        path: PathBuf,
+    },
+    PermissionDenied {
+        path: PathBuf,
+    },
+    // 위 두 경우가 아닌 나머지 입출력 에러 (디렉터리를 읽으려고 했다든지)
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    InvalidUtf8 {
+        path: PathBuf,
+        source: Utf8Error,
+    },
+    // 파일이 비어있거나 공백뿐이다.
+    Empty {
+        path: PathBuf,
+    },
+    // 이름은 있지만 형식에 맞지 않는다.
+    InvalidFormat {
+        path: PathBuf,
+        username: String,
+        reason: &'static str,
+    },
+}
+
+impl UsernameError {
+    // 어떤 에러든 읽으려던 파일의 경로를 가지고 있다.
+    pub fn path(&self) -> &Path {
+        match self {
+            UsernameError::NotFound { path }
+            | UsernameError::PermissionDenied { path }
+            | UsernameError::Io { path, .. }
+            | UsernameError::InvalidUtf8 { path, .. }
+            | UsernameError::Empty { path }
+            | UsernameError::InvalidFormat { path, .. } => path,
+        }
+    }
+
+    // io::Error의 종류에 따라 알맞은 variant로 바꾼다.
+    fn from_io(path: &Path, error: io::Error) -> UsernameError {
+        let path = path.to_path_buf();
+        match error.kind() {
+            ErrorKind::NotFound => UsernameError::NotFound { path },
+            ErrorKind::PermissionDenied => UsernameError::PermissionDenied { path },
+            _ => UsernameError::Io {
+                path,
+                source: error,
+            },
+        }
+    }
+}
+
+impl fmt::Display for UsernameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = self.path().display();
+        match self {
+            UsernameError::NotFound { .. } => write!(f, "{}: file not found", path),
+            UsernameError::PermissionDenied { .. } => write!(f, "{}: permission denied", path),
+            UsernameError::Io { source, .. } => write!(f, "{}: {}", path, source),
+            UsernameError::InvalidUtf8 { source, .. } => write!(
+                f,
+                "{}: not valid UTF-8 (after byte {})",
+                path,
+                source.valid_up_to()
+            ),
+            UsernameError::Empty { .. } => write!(f, "{}: no username in the file", path),
+            UsernameError::InvalidFormat {
+                username, reason, ..
+            } => write!(f, "{}: invalid username '{}': {}", path, username, reason),
+        }
+    }
+}
+
+impl Error for UsernameError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            UsernameError::Io { source, .. } => Some(source),
+            UsernameError::InvalidUtf8 { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// 파일에서 사용자 이름을 읽어서 검사한다. 앞뒤의 공백과 줄바꿈은 무시한다.
+// 이름은 영문자로 시작하고, 영문자, 숫자, '_', '-'로만 이루어지며, MAX_LEN 글자를 넘지 않아야 한다.
+// 읽다가 잠깐 일어나는 에러(Interrupted 등)가 나면 기본 Backoff대로 기다렸다가 다시 읽는다.
+pub fn load_username<P: AsRef<Path>>(path: P) -> Result<String, UsernameError> {
+    load_username_retrying(&RealFileSystem, path, &Backoff::default(), &mut SystemClock)
+}
+
+// load_username과 같지만 fs에서 읽고, backoff대로 clock에서 기다리며 다시 시도한다.
+pub fn load_username_retrying<F, P, C>(
+    fs: &F,
+    path: P,
+    backoff: &Backoff,
+    clock: &mut C,
+) -> Result<String, UsernameError>
+where
+    F: FileSystem,
+    P: AsRef<Path>,
+    C: Clock + ?Sized,
+{
+    let path = path.as_ref();
+    backoff.retry(clock, || load_username_in(fs, path))
+}
+
+// fs에서 한 번만 읽어본다. MemoryFileSystem을 넘기면 디스크 없이 테스트할 수 있다.
+pub fn load_username_in<F: FileSystem, P: AsRef<Path>>(
+    fs: &F,
+    path: P,
+) -> Result<String, UsernameError> {
+    let path = path.as_ref();
+    // ?는 Err이면 함수에서 바로 반환하고, Ok이면 값을 꺼내준다.
+    // 반환 타입이 Result<_, UsernameError>이므로 io::Error는 map_err로 바꿔서 넘긴다.
+    // read_to_string은 UTF-8이 아니면 InvalidData라는 io::Error만 주므로, 바이트로 읽어서 직접 확인한다.
+    let bytes = fs.read(path).map_err(|e| UsernameError::from_io(path, e))?;
+    let text = str::from_utf8(&bytes).map_err(|source| UsernameError::InvalidUtf8 {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let username = text.trim();
+    if username.is_empty() {
+        return Err(UsernameError::Empty {
+            path: path.to_path_buf(),
+        });
+    }
+    if let Err(reason) = validate(username) {
+        return Err(UsernameError::InvalidFormat {
+            path: path.to_path_buf(),
+            username: username.to_string(),
+            reason,
+        });
+    }
+    Ok(username.to_string())
+}
+
+fn validate(username: &str) -> Result<(), &'static str> {
+    if username.chars().count() > MAX_LEN {
+        return Err("too long");
+    }
+    if !username.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err("must start with a letter");
+    }
+    if username.chars().any(char::is_whitespace) {
+        return Err("must not contain spaces or line breaks");
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err("only letters, digits, '_' and '-' are allowed");
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+
+use errors::bounded::{Bounded, BoundedError};
+use errors::guess::Guess;
+
+type Percent = Bounded<u8, 0, 100>;
+type Temperature = Bounded<i64, -40, 50>;
+
+#[test]
+fn try_new_accepts_only_values_in_range() {
+    assert_eq!(Guess::try_new(1).unwrap().value(), 1);
+    assert_eq!(Guess::try_new(100).unwrap().value(), 100);
+    assert_eq!(
+        Temperature::try_new(-41),
+        Err(BoundedError::TooLow { min: -40, got: -41 })
+    );
+    assert_eq!(
+        Percent::try_new(101),
+        Err(BoundedError::TooHigh { max: 100, got: 101 })
+    );
+    assert!(Temperature::try_new(-40).is_ok());
+    assert_eq!((Percent::min(), Percent::max()), (0, 100));
+}
+
+#[test]
+#[should_panic(expected = "value must be between 1 and 100, got 200.")]
+fn new_still_panics_out_of_range() {
+    Guess::new(200);
+}
+
+#[test]
+fn clamping_and_saturating_constructors_stay_in_range() {
+    assert_eq!(Guess::clamped(-5).value(), 1);
+    assert_eq!(Guess::clamped(500).value(), 100);
+    assert_eq!(Guess::clamped(42).value(), 42);
+    // u8에 담을 수 없는 값도 받는다.
+    assert_eq!(Percent::saturating_from(-1_i64).value(), 0);
+    assert_eq!(Percent::saturating_from(u64::MAX).value(), 100);
+}
+
+#[test]
+fn conversions_from_numbers_and_strings() {
+    assert_eq!(Guess::try_from(7).unwrap(), Guess::new(7));
+    assert!(Percent::try_from(101_u8).is_err());
+
+    assert_eq!(" 42\n".parse::<Guess>().unwrap().value(), 42);
+    // u8로 읽을 수 없는 값은 범위를 따지기 전에 숫자가 아니다.
+    assert!(matches!(
+        "-1".parse::<Percent>(),
+        Err(BoundedError::Invalid(_))
+    ));
+    assert_eq!(
+        "-41".parse::<Temperature>(),
+        Err(BoundedError::TooLow { min: -40, got: -41 })
+    );
+}
+
+#[test]
+fn display_shows_the_value() {
+    assert_eq!(Guess::new(55).to_string(), "55");
+    assert_eq!(Temperature::new(-3).to_string(), "-3");
+}
+
+#[test]
+fn checked_arithmetic_never_leaves_the_range() {
+    let guess = Guess::new(90);
+    assert_eq!(guess.checked_add(10), Some(Guess::new(100)));
+    assert_eq!(guess.checked_add(11), None);
+    assert_eq!(guess.checked_sub(89), Some(Guess::new(1)));
+    assert_eq!(guess.checked_sub(90), None);
+    assert_eq!(Guess::new(10).checked_mul(10), Some(Guess::new(100)));
+    assert_eq!(Guess::new(10).checked_mul(-1), None);
+    // i32 자체가 넘치는 계산도 None이다.
+    assert_eq!(guess.checked_add(i32::MAX), None);
+
+    let full = Bounded::<u64, 0, { u64::MAX as i128 }>::new(u64::MAX);
+    assert_eq!(full.checked_mul(u64::MAX), None);
+    assert_eq!(full.checked_sub(1).map(|v| v.value()), Some(u64::MAX - 1));
+}
@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+
+use errors::config::{Config, ConfigError, Origin, Value};
+use errors::report::Category;
+
+const TEXT: &str = "\
+# 주석은 건너뛴다.
+name = \"ferris\"
+level = 3
+
+[server]
+port = \"seven\"
+retries = { max = 5 }
+";
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn toml_values_are_typed_and_keep_their_line() {
+    let config = Config::parse("app.toml", TEXT).unwrap();
+    assert_eq!(
+        config.get::<String>("name").unwrap().as_deref(),
+        Some("ferris")
+    );
+    assert_eq!(config.get::<u32>("level").unwrap(), Some(3));
+    assert_eq!(config.get::<u64>("server.retries.max").unwrap(), Some(5));
+    assert_eq!(config.get::<u32>("missing").unwrap(), None);
+    assert_eq!(
+        config.origin("server.port"),
+        Some(&Origin::File {
+            path: PathBuf::from("app.toml"),
+            line: 6
+        })
+    );
+}
+
+#[test]
+fn wrong_types_name_the_file_line_key_and_expected_type() {
+    let config = Config::parse("app.toml", TEXT).unwrap();
+    let err = config.get::<u16>("server.port").unwrap_err();
+    match &err {
+        ConfigError::WrongType { key, expected, .. } => {
+            assert_eq!(key, "server.port");
+            assert_eq!(expected, "an integer between 0 and 65535");
+        }
+        other => panic!("expected WrongType, got {:?}", other),
+    }
+    assert_eq!(
+        err.to_string(),
+        "app.toml:6: server.port must be an integer between 0 and 65535, found a string (\"seven\")"
+    );
+    assert_eq!(Category::of(&err), Category::Config);
+
+    // 범위를 벗어난 정수도 타입이 맞지 않는 것으로 본다.
+    let config = Config::parse("app.toml", "level = -1").unwrap();
+    assert!(matches!(
+        config.get::<u32>("level"),
+        Err(ConfigError::WrongType { .. })
+    ));
+}
+
+#[test]
+fn syntax_errors_point_at_the_line() {
+    let err = Config::parse("app.toml", "name = \"ferris\"\nlevel = = 3\n").unwrap_err();
+    match err {
+        ConfigError::Syntax { path, line, .. } => {
+            assert_eq!(path, Path::new("app.toml"));
+            assert_eq!(line, 2);
+        }
+        other => panic!("expected Syntax, got {:?}", other),
+    }
+}
+
+#[test]
+fn missing_files_are_io_errors_unless_optional() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.toml");
+    let err = Config::load(&path).unwrap_err();
+    assert!(matches!(err, ConfigError::Io { .. }));
+    assert_eq!(Category::of(&err), Category::NotFound);
+    assert_eq!(Config::load_if_exists(&path).unwrap(), Config::new());
+
+    std::fs::write(&path, "level = 7\n").unwrap();
+    let config = Config::load(&path).unwrap();
+    assert_eq!(config.get::<u32>("level").unwrap(), Some(7));
+}
+
+#[test]
+fn layers_overwrite_in_order() {
+    let file = Config::parse("app.toml", TEXT).unwrap();
+    let env = Config::from_env(
+        "APP",
+        vars(&[
+            ("APP_LEVEL", "4"),
+            ("APP_SERVER__PORT", "8080"),
+            ("HOME", "/root"),
+        ]),
+    );
+    let mut flags = Config::new();
+    flags.set(
+        "level",
+        Value::Text("5".to_string()),
+        Origin::Flag {
+            flag: "--level".to_string(),
+        },
+    );
+
+    let config = file.merge(env).merge(flags);
+    assert_eq!(config.get::<u32>("level").unwrap(), Some(5));
+    assert_eq!(config.get::<u16>("server.port").unwrap(), Some(8080));
+    assert_eq!(
+        config.get::<String>("name").unwrap().as_deref(),
+        Some("ferris")
+    );
+    // 접두어가 다른 환경 변수는 읽지 않는다.
+    assert!(config.keys().all(|key| key != "home"));
+
+    let config = config.merge(Config::from_env("APP", vars(&[("APP_LEVEL", "lots")])));
+    assert_eq!(
+        config.get::<u32>("level").unwrap_err().to_string(),
+        "environment variable APP_LEVEL: level must be an integer between 0 and 4294967295, found \"lots\""
+    );
+}
+
+#[test]
+fn conversions_and_unknown_keys_are_reported() {
+    let config = Config::parse("app.toml", "mode = \"fast\"\nlevle = 3\n").unwrap();
+    let err = config
+        .get_with("mode", |mode: String| match mode.as_str() {
+            "slow" => Ok(1),
+            other => Err(format!("unknown mode {}", other)),
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "app.toml:1: invalid mode: unknown mode fast"
+    );
+
+    let err = config.check_keys(&["mode", "level"]).unwrap_err();
+    assert_eq!(err.to_string(), "app.toml:2: unknown key levle");
+
+    // 설정에 없는 키라도 panic하지 않고 위치 없이 알린다.
+    let err = config.invalid("speed", "must be set together with mode");
+    assert!(matches!(
+        err,
+        ConfigError::InvalidValue { origin: None, .. }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "invalid speed: must be set together with mode"
+    );
+}
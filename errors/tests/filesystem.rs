@@ -0,0 +1,150 @@
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+
+use errors::filesystem::{Fault, FileSystem, MemoryFileSystem};
+use errors::fsutil::{self, Operation};
+use errors::username::{load_username_in, UsernameError};
+
+#[test]
+fn memory_files_can_be_written_and_read_back() {
+    let fs = MemoryFileSystem::new().with_dir("home");
+    let path = Path::new("home/hello.txt");
+
+    let mut file = fs.create(path).unwrap();
+    file.write_all(b"hello, ").unwrap();
+    file.write_all(b"world").unwrap();
+    assert_eq!(fs.read(path).unwrap(), b"hello, world");
+
+    let mut text = String::new();
+    fs.open(path).unwrap().read_to_string(&mut text).unwrap();
+    assert_eq!(text, "hello, world");
+
+    // 없는 디렉터리 안에는 만들 수 없다.
+    let err = fs.create(Path::new("nowhere/hello.txt")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert_eq!(
+        fs.create_new(path).unwrap_err().kind(),
+        ErrorKind::AlreadyExists
+    );
+}
+
+#[test]
+fn username_is_read_through_the_file_system() {
+    let fs = MemoryFileSystem::new().with_file("hello.txt", b"ferris\n");
+    assert_eq!(load_username_in(&fs, "hello.txt").unwrap(), "ferris");
+}
+
+#[test]
+fn username_not_found() {
+    let fs = MemoryFileSystem::new();
+    let err = load_username_in(&fs, "hello.txt").unwrap_err();
+    assert!(matches!(err, UsernameError::NotFound { .. }));
+
+    // 파일이 있어도 NotFound를 심어두면 없는 것처럼 보인다.
+    let fs = MemoryFileSystem::new()
+        .with_file("hello.txt", b"ferris")
+        .with_fault("hello.txt", Fault::Fail(ErrorKind::NotFound));
+    let err = load_username_in(&fs, "hello.txt").unwrap_err();
+    assert!(matches!(err, UsernameError::NotFound { .. }));
+}
+
+#[test]
+fn username_permission_denied() {
+    // 실제 디스크와 달리 root로 실행해도 확인할 수 있다.
+    let fs = MemoryFileSystem::new()
+        .with_file("secret/hello.txt", b"ferris")
+        .with_fault("secret", Fault::Fail(ErrorKind::PermissionDenied));
+    let err = load_username_in(&fs, "secret/hello.txt").unwrap_err();
+    assert!(matches!(err, UsernameError::PermissionDenied { .. }));
+    assert_eq!(err.path(), Path::new("secret/hello.txt"));
+}
+
+#[test]
+fn username_interrupted_is_an_io_error() {
+    let fs = MemoryFileSystem::new()
+        .with_file("hello.txt", b"ferris")
+        .with_fault("hello.txt", Fault::FailTimes(ErrorKind::Interrupted, 1));
+    match load_username_in(&fs, "hello.txt") {
+        Err(UsernameError::Io { source, .. }) => assert_eq!(source.kind(), ErrorKind::Interrupted),
+        other => panic!("expected Io, got {:?}", other),
+    }
+    // 한 번만 실패하도록 심었으므로 다시 읽으면 성공한다.
+    assert_eq!(load_username_in(&fs, "hello.txt").unwrap(), "ferris");
+}
+
+#[test]
+fn username_invalid_utf8() {
+    let fs = MemoryFileSystem::new()
+        .with_file("hello.txt", b"ferris")
+        .with_fault("hello.txt", Fault::InvalidUtf8);
+    match load_username_in(&fs, "hello.txt") {
+        Err(UsernameError::InvalidUtf8 { source, .. }) => assert_eq!(source.valid_up_to(), 6),
+        other => panic!("expected InvalidUtf8, got {:?}", other),
+    }
+}
+
+#[test]
+fn username_from_a_directory() {
+    let fs = MemoryFileSystem::new().with_dir("hello.txt");
+    match load_username_in(&fs, "hello.txt") {
+        Err(UsernameError::Io { source, .. }) => assert_eq!(source.kind(), ErrorKind::IsADirectory),
+        other => panic!("expected Io, got {:?}", other),
+    }
+}
+
+#[test]
+fn open_or_create_creates_missing_files_only() {
+    let fs = MemoryFileSystem::new();
+    fsutil::open_or_create_in(&fs, "a/b/hello.txt")
+        .unwrap()
+        .write_all(b"hello")
+        .unwrap();
+    assert_eq!(fs.contents("a/b/hello.txt").unwrap(), b"hello");
+
+    // 이미 있으면 내용을 건드리지 않는다.
+    fsutil::open_or_create_in(&fs, "a/b/hello.txt").unwrap();
+    assert_eq!(fs.contents("a/b/hello.txt").unwrap(), b"hello");
+}
+
+#[test]
+fn open_or_create_does_not_replace_unreadable_files() {
+    for kind in [ErrorKind::PermissionDenied, ErrorKind::Interrupted] {
+        let fs = MemoryFileSystem::new()
+            .with_file("hello.txt", b"hello")
+            .with_fault("hello.txt", Fault::Fail(kind));
+        let err = fsutil::open_or_create_in(&fs, "hello.txt").unwrap_err();
+        assert_eq!(err.operation(), Operation::Open);
+        assert_eq!(err.kind(), kind);
+        assert_eq!(fs.contents("hello.txt").unwrap(), b"hello");
+    }
+}
+
+#[test]
+fn errors_report_which_step_failed() {
+    // 파일 아래에는 디렉터리를 만들 수 없다.
+    let fs = MemoryFileSystem::new().with_file("blocker", b"");
+    let err = fsutil::open_or_create_in(&fs, "blocker/inner/hello.txt").unwrap_err();
+    assert_eq!(err.operation(), Operation::Open);
+    assert_eq!(err.kind(), ErrorKind::NotADirectory);
+
+    let err = fsutil::write_atomic_in(&fs, "blocker/inner/hello.txt", b"x").unwrap_err();
+    assert_eq!(err.operation(), Operation::CreateDir);
+    assert_eq!(err.path(), Path::new("blocker/inner"));
+    assert_eq!(err.kind(), ErrorKind::NotADirectory);
+}
+
+#[test]
+fn failed_atomic_writes_keep_the_old_contents() {
+    let fs = MemoryFileSystem::new()
+        .with_file("scores.json", b"[1]")
+        .with_fault("scores.json", Fault::FailTimes(ErrorKind::Interrupted, 1));
+    let err = fsutil::write_atomic_in(&fs, "scores.json", b"[1, 2]").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Interrupted);
+    assert_eq!(fs.contents("scores.json").unwrap(), b"[1]");
+    // 임시 파일이 남지 않는다.
+    assert_eq!(fs.files(), [PathBuf::from("scores.json")]);
+
+    fsutil::write_atomic_in(&fs, "scores.json", b"[1, 2]").unwrap();
+    assert_eq!(fs.contents("scores.json").unwrap(), b"[1, 2]");
+    assert_eq!(fs.files(), [PathBuf::from("scores.json")]);
+}
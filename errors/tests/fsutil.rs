@@ -0,0 +1,106 @@
+use std::error::Error;
+use std::fs;
+use std::io::{ErrorKind, Read, Write};
+
+use errors::fsutil::{self, Operation};
+
+#[test]
+fn open_or_create_makes_missing_files_and_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("a/b/hello.txt");
+
+    let mut file = fsutil::open_or_create(&path).unwrap();
+    file.write_all(b"hello").unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"hello");
+
+    // 이미 있으면 내용을 건드리지 않고 연다.
+    let mut text = String::new();
+    fsutil::open_or_create(&path)
+        .unwrap()
+        .read_to_string(&mut text)
+        .unwrap();
+    assert_eq!(text, "hello");
+}
+
+#[test]
+fn open_or_init_writes_defaults_only_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config/settings.toml");
+
+    let mut text = String::new();
+    fsutil::open_or_init(&path, b"level = 1\n")
+        .unwrap()
+        .read_to_string(&mut text)
+        .unwrap();
+    assert_eq!(text, "level = 1\n");
+
+    fs::write(&path, "level = 2\n").unwrap();
+    let mut text = String::new();
+    fsutil::open_or_init(&path, b"level = 1\n")
+        .unwrap()
+        .read_to_string(&mut text)
+        .unwrap();
+    assert_eq!(text, "level = 2\n");
+}
+
+#[test]
+fn write_atomic_replaces_contents_without_leftovers() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("scores.json");
+    fsutil::write_atomic(&path, b"[1]").unwrap();
+    fsutil::write_atomic(&path, b"[1, 2]").unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"[1, 2]");
+    // 임시 파일이 남지 않는다.
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn errors_carry_the_operation_and_path() {
+    let dir = tempfile::tempdir().unwrap();
+    // 파일 아래에는 디렉터리를 만들 수 없다.
+    let blocker = dir.path().join("blocker");
+    fs::write(&blocker, "").unwrap();
+    let path = blocker.join("inner/hello.txt");
+
+    // 여는 것부터 실패한다. (없는 것이 아니라 경로가 잘못되었다)
+    let err = fsutil::open_or_create(&path).unwrap_err();
+    assert_eq!(err.operation(), Operation::Open);
+    assert_eq!(err.path(), path);
+
+    let err = fsutil::write_atomic(&path, b"x").unwrap_err();
+    assert_eq!(err.operation(), Operation::CreateDir);
+    assert_eq!(err.path(), blocker.join("inner"));
+    assert!(err.source().is_some());
+    assert!(err.to_string().starts_with(&format!(
+        "failed to create directory {}: ",
+        err.path().display()
+    )));
+
+    let err = fsutil::write_atomic(dir.path(), b"x").unwrap_err();
+    assert_eq!(err.operation(), Operation::Rename);
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn permissions_are_kept_and_denials_are_not_treated_as_missing() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("secret.txt");
+    fsutil::write_atomic(&path, b"one").unwrap();
+    fsutil::set_mode(&path, 0o600).unwrap();
+    fsutil::write_atomic(&path, b"two").unwrap();
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    fsutil::set_mode(&path, 0o000).unwrap();
+    // root는 권한과 상관없이 열 수 있으므로 확인할 수 없다.
+    if fs::File::open(&path).is_ok() {
+        return;
+    }
+    let err = fsutil::open_or_create(&path).unwrap_err();
+    assert!(err.is_permission_denied());
+    assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    assert_eq!(err.operation(), Operation::Open);
+}
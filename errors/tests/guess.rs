@@ -0,0 +1,60 @@
+use std::error::Error;
+
+use errors::guess::{Guess, GuessError};
+
+#[test]
+fn try_new_tells_which_side_is_wrong() {
+    assert_eq!(Guess::try_new(50).map(|guess| guess.value()), Ok(50));
+    assert_eq!(
+        Guess::try_new(0),
+        Err(GuessError::TooLow { min: 1, got: 0 })
+    );
+    assert_eq!(
+        Guess::try_new(101),
+        Err(GuessError::TooHigh { max: 100, got: 101 })
+    );
+}
+
+#[test]
+fn text_goes_straight_to_a_guess() {
+    assert_eq!("42".parse::<Guess>(), Ok(Guess::new(42)));
+    assert_eq!(
+        "-7".parse::<Guess>(),
+        Err(GuessError::TooLow { min: 1, got: -7 })
+    );
+    assert_eq!(
+        "1000".parse::<Guess>(),
+        Err(GuessError::TooHigh {
+            max: 100,
+            got: 1000
+        })
+    );
+    assert!(matches!(
+        "abc".parse::<Guess>(),
+        Err(GuessError::Invalid(_))
+    ));
+    assert!(matches!("".parse::<Guess>(), Err(GuessError::Invalid(_))));
+}
+
+// ?로 GuessError를 Box<dyn Error>로 넘길 수 있다.
+fn read_guess(text: &str) -> Result<Guess, Box<dyn Error>> {
+    Ok(text.parse::<Guess>()?)
+}
+
+#[test]
+fn guess_error_is_a_std_error() {
+    let err = read_guess("0").unwrap_err();
+    assert_eq!(err.to_string(), "value must be at least 1, got 0");
+    assert!(err.source().is_none());
+
+    let err = read_guess("150").unwrap_err();
+    assert_eq!(err.to_string(), "value must be at most 100, got 150");
+
+    let err = read_guess("ten").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "not a valid number: invalid digit found in string"
+    );
+    // 숫자로 읽지 못한 원인(ParseIntError)을 source로 알 수 있다.
+    assert!(err.source().is_some());
+}
@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+use errors::guess::Guess;
+use errors::report::{self, Category, Context, Format, Report};
+use errors::username::UsernameError;
+
+fn io_error(kind: ErrorKind) -> io::Error {
+    io::Error::new(kind, "boom")
+}
+
+#[test]
+fn io_errors_are_categorized_by_kind() {
+    let cases = [
+        (ErrorKind::NotFound, Category::NotFound, 66),
+        (ErrorKind::PermissionDenied, Category::PermissionDenied, 77),
+        (ErrorKind::InvalidData, Category::InvalidData, 65),
+        (ErrorKind::ConnectionRefused, Category::Unavailable, 69),
+        (ErrorKind::Interrupted, Category::Io, 74),
+    ];
+    for (kind, category, code) in cases.iter() {
+        let report = Report::without_backtrace(&io_error(*kind));
+        assert_eq!(report.category, *category, "{:?}", kind);
+        assert_eq!(report.exit_code(), *code);
+    }
+}
+
+#[test]
+fn library_errors_are_categorized() {
+    let missing = UsernameError::NotFound {
+        path: PathBuf::from("hello.txt"),
+    };
+    assert_eq!(Category::of(&missing), Category::NotFound);
+
+    let empty = UsernameError::Empty {
+        path: PathBuf::from("hello.txt"),
+    };
+    assert_eq!(Category::of(&empty), Category::InvalidData);
+
+    let out_of_range = "150".parse::<Guess>().unwrap_err();
+    assert_eq!(Category::of(&out_of_range), Category::InvalidData);
+
+    // 종류를 알 수 없는 에러는 Failure다.
+    let unknown: Box<dyn Error> = "something went wrong".into();
+    assert_eq!(Category::of(&*unknown), Category::Failure);
+}
+
+#[test]
+fn context_keeps_the_source_chain() {
+    let result: Result<(), io::Error> = Err(io_error(ErrorKind::NotFound));
+    let err = result.context("Failed to read high scores").unwrap_err();
+
+    let messages: Vec<String> = report::chain(&err).map(|e| e.to_string()).collect();
+    assert_eq!(messages, ["Failed to read high scores", "boom"]);
+    // 바깥 에러는 종류를 모르지만 원인을 보고 정한다.
+    assert_eq!(Category::of(&err), Category::NotFound);
+
+    let report = Report::without_backtrace(&err);
+    assert_eq!(report.message, "Failed to read high scores");
+    assert_eq!(report.causes, ["boom"]);
+}
+
+#[test]
+fn text_output_lists_causes_and_backtrace() {
+    let result: Result<(), io::Error> = Err(io_error(ErrorKind::PermissionDenied));
+    let err = result.context("Failed to save high scores").unwrap_err();
+    let report = Report::without_backtrace(&err);
+
+    let mut out = Vec::new();
+    report.write("game", Format::Text, &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "game: Failed to save high scores\n  caused by: boom\n"
+    );
+
+    let report = report.with_backtrace(Some("0: main".to_string()));
+    let mut out = Vec::new();
+    report.write_text("game", &mut out).unwrap();
+    assert!(String::from_utf8(out)
+        .unwrap()
+        .ends_with("\nbacktrace:\n0: main\n"));
+}
+
+#[test]
+fn json_output_has_one_line_with_all_fields() {
+    let unknown: Box<dyn Error> = "unknown argument: --bogus".into();
+    let report = Report::without_backtrace(&*unknown).with_category(Category::Usage);
+
+    let mut out = Vec::new();
+    report.write("game", Format::Json, &mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(text.lines().count(), 1);
+
+    let value: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(value["program"], "game");
+    assert_eq!(value["error"], "unknown argument: --bogus");
+    assert_eq!(value["category"], "usage");
+    assert_eq!(value["exit_code"], 2);
+    assert_eq!(value["causes"], serde_json::json!([]));
+    assert!(value["backtrace"].is_null());
+}
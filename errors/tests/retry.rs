@@ -0,0 +1,112 @@
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use errors::filesystem::{Fault, MemoryFileSystem};
+use errors::retry::{classify, Backoff, Class, RecordingClock, Transient};
+use errors::username::{load_username_retrying, UsernameError};
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn error_kinds_are_classified() {
+    for kind in [
+        ErrorKind::Interrupted,
+        ErrorKind::WouldBlock,
+        ErrorKind::TimedOut,
+    ] {
+        assert_eq!(classify(kind), Class::Transient, "{:?}", kind);
+    }
+    for kind in [
+        ErrorKind::NotFound,
+        ErrorKind::PermissionDenied,
+        ErrorKind::InvalidData,
+    ] {
+        assert_eq!(classify(kind), Class::Permanent, "{:?}", kind);
+    }
+
+    let empty = UsernameError::Empty {
+        path: PathBuf::from("hello.txt"),
+    };
+    assert!(!empty.is_transient());
+    let interrupted = UsernameError::Io {
+        path: PathBuf::from("hello.txt"),
+        source: io::Error::from(ErrorKind::Interrupted),
+    };
+    assert!(interrupted.is_transient());
+}
+
+#[test]
+fn delays_grow_exponentially_up_to_the_limit() {
+    let backoff = Backoff::new()
+        .with_initial_delay(ms(100))
+        .with_multiplier(3)
+        .with_max_delay(ms(1000));
+    let delays: Vec<Duration> = (0..5).map(|retry| backoff.delay(retry)).collect();
+    assert_eq!(delays, [ms(100), ms(300), ms(900), ms(1000), ms(1000)]);
+    // 곱하다 넘쳐도 max_delay에서 멈춘다.
+    assert_eq!(backoff.delay(u32::MAX), ms(1000));
+}
+
+#[test]
+fn transient_errors_are_retried_until_success() {
+    let backoff = Backoff::new().with_initial_delay(ms(10));
+    let mut clock = RecordingClock::new();
+    let mut failures = vec![ErrorKind::TimedOut, ErrorKind::WouldBlock];
+
+    let result: Result<&str, io::Error> = backoff.retry(&mut clock, || match failures.pop() {
+        Some(kind) => Err(io::Error::from(kind)),
+        None => Ok("done"),
+    });
+    assert_eq!(result.unwrap(), "done");
+    assert_eq!(clock.sleeps(), [ms(10), ms(20)]);
+}
+
+#[test]
+fn permanent_errors_are_returned_at_once() {
+    let mut clock = RecordingClock::new();
+    let mut attempts = 0;
+    let result: Result<(), io::Error> = Backoff::new().retry(&mut clock, || {
+        attempts += 1;
+        Err(io::Error::from(ErrorKind::PermissionDenied))
+    });
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::PermissionDenied);
+    assert_eq!(attempts, 1);
+    assert!(clock.sleeps().is_empty());
+}
+
+#[test]
+fn gives_up_after_max_attempts() {
+    let backoff = Backoff::new().with_max_attempts(3);
+    let mut clock = RecordingClock::new();
+    let mut attempts = 0;
+    let result: Result<(), io::Error> = backoff.retry(&mut clock, || {
+        attempts += 1;
+        Err(io::Error::from(ErrorKind::Interrupted))
+    });
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::Interrupted);
+    assert_eq!(attempts, 3);
+    assert_eq!(clock.sleeps().len(), 2);
+}
+
+#[test]
+fn username_reads_are_retried() {
+    let fs = MemoryFileSystem::new()
+        .with_file("hello.txt", b"ferris")
+        .with_fault("hello.txt", Fault::FailTimes(ErrorKind::Interrupted, 2));
+    let mut clock = RecordingClock::new();
+    let username = load_username_retrying(&fs, "hello.txt", &Backoff::new(), &mut clock);
+    assert_eq!(username.unwrap(), "ferris");
+    assert_eq!(clock.total(), ms(30));
+
+    // 권한이 없는 것은 기다려도 바뀌지 않는다.
+    let fs = MemoryFileSystem::new()
+        .with_file("hello.txt", b"ferris")
+        .with_fault("hello.txt", Fault::Fail(ErrorKind::PermissionDenied));
+    let mut clock = RecordingClock::new();
+    let err = load_username_retrying(&fs, "hello.txt", &Backoff::new(), &mut clock).unwrap_err();
+    assert!(matches!(err, UsernameError::PermissionDenied { .. }));
+    assert!(clock.sleeps().is_empty());
+}
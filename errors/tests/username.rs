@@ -0,0 +1,98 @@
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+use errors::username::{load_username, UsernameError};
+use tempfile::TempDir;
+
+// 임시 디렉터리에 contents를 가진 파일을 만든다. TempDir이 drop되면 디렉터리째 지워진다.
+fn file_with(contents: &[u8]) -> (TempDir, PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("username.txt");
+    fs::write(&path, contents).unwrap();
+    (dir, path)
+}
+
+#[test]
+fn username_is_trimmed() {
+    let (_dir, path) = file_with(b"  ferris_42\n");
+    assert_eq!(load_username(&path).unwrap(), "ferris_42");
+}
+
+#[test]
+fn missing_file_keeps_the_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nobody.txt");
+    let err = load_username(&path).unwrap_err();
+    assert!(matches!(err, UsernameError::NotFound { .. }));
+    assert_eq!(err.path(), path);
+    assert_eq!(
+        err.to_string(),
+        format!("{}: file not found", path.display())
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn unreadable_file_is_permission_denied() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (_dir, path) = file_with(b"ferris");
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o000)).unwrap();
+    // root는 권한과 상관없이 읽을 수 있으므로 확인할 수 없다.
+    if fs::read(&path).is_ok() {
+        return;
+    }
+    let err = load_username(&path).unwrap_err();
+    assert!(matches!(err, UsernameError::PermissionDenied { .. }));
+}
+
+#[test]
+fn directories_are_other_io_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = load_username(dir.path()).unwrap_err();
+    assert!(matches!(err, UsernameError::Io { .. }));
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn invalid_utf8_is_reported() {
+    let (_dir, path) = file_with(b"fer\xffris");
+    let err = load_username(&path).unwrap_err();
+    match &err {
+        UsernameError::InvalidUtf8 { source, .. } => assert_eq!(source.valid_up_to(), 3),
+        other => panic!("expected InvalidUtf8, got {:?}", other),
+    }
+    assert!(err.to_string().ends_with("not valid UTF-8 (after byte 3)"));
+}
+
+#[test]
+fn empty_and_blank_files_have_no_username() {
+    for contents in [&b""[..], b"   \n\t\n"] {
+        let (_dir, path) = file_with(contents);
+        assert!(matches!(
+            load_username(&path),
+            Err(UsernameError::Empty { .. })
+        ));
+    }
+}
+
+#[test]
+fn username_format_is_validated() {
+    let cases: [(&[u8], &str); 4] = [
+        (b"42ferris", "must start with a letter"),
+        (b"ferris crab", "must not contain spaces or line breaks"),
+        (b"ferris!", "only letters, digits, '_' and '-' are allowed"),
+        (&[b'a'; 33], "too long"),
+    ];
+    for (contents, expected) in cases.iter() {
+        let (_dir, path) = file_with(contents);
+        match load_username(&path) {
+            Err(UsernameError::InvalidFormat { reason, .. }) => assert_eq!(reason, *expected),
+            other => panic!("expected InvalidFormat, got {:?}", other),
+        }
+    }
+    // 최대 길이까지는 괜찮다.
+    let (_dir, path) = file_with(&[b'a'; 32]);
+    assert!(load_username(&path).is_ok());
+}
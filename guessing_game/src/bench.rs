@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::difficulty::Difficulty;
+use crate::engine::GameEngine;
+use crate::messages::Messages;
+use crate::secret::SecretSource;
+use crate::solver::{solve, Strategy};
+
+// 여러 판을 돌린 결과를 모아둔다.
+#[derive(Debug, Default, PartialEq)]
+pub struct BenchReport {
+    pub games: u32,
+    pub wins: u32,
+    pub total_attempts: u64,
+    pub max_attempts: u32,
+    // 시도 횟수 -> 그 횟수가 걸린 판 수. BTreeMap이라 키 순서대로 순회된다.
+    pub histogram: BTreeMap<u32, u32>,
+}
+
+impl BenchReport {
+    pub fn mean_attempts(&self) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+        self.total_attempts as f64 / f64::from(self.games)
+    }
+
+    pub fn print<W: Write>(&self, messages: &Messages, mut output: W) -> io::Result<()> {
+        let summary = messages.format(
+            "bench.summary",
+            &[
+                ("games", &self.games),
+                ("wins", &self.wins),
+                ("losses", &(self.games - self.wins)),
+            ],
+        );
+        writeln!(output, "{}", summary)?;
+        let mean = format!("{:.2}", self.mean_attempts());
+        let attempts = messages.format(
+            "bench.attempts",
+            &[("mean", &mean), ("max", &self.max_attempts)],
+        );
+        writeln!(output, "{}", attempts)?;
+
+        // 가장 많은 칸이 40개의 #가 되도록 맞춘다.
+        let widest = self.histogram.values().max().copied().unwrap_or(0);
+        for (attempts, count) in &self.histogram {
+            let width = (*count as usize * 40).div_ceil(widest.max(1) as usize);
+            writeln!(
+                output,
+                "{:>4} | {:<40} {}",
+                attempts,
+                "#".repeat(width),
+                count
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// 같은 난이도(범위, 시도 횟수 제한)로 games 판을 돌린다.
+pub fn run(
+    difficulty: Difficulty,
+    games: u32,
+    strategy: &mut dyn Strategy,
+    source: &mut dyn SecretSource,
+) -> BenchReport {
+    let range = difficulty.range();
+    let mut report = BenchReport::default();
+
+    for _ in 0..games {
+        let secret_number = source.next_secret(range.min(), range.max());
+        let mut engine =
+            GameEngine::new(secret_number, range).with_max_attempts(difficulty.max_attempts());
+        let solved = solve(&mut engine, strategy);
+
+        report.games += 1;
+        if solved.won {
+            report.wins += 1;
+        }
+        report.total_attempts += u64::from(solved.attempts);
+        report.max_attempts = report.max_attempts.max(solved.attempts);
+        *report.histogram.entry(solved.attempts).or_insert(0) += 1;
+    }
+
+    report
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::messages::{Lang, Messages};
+use crate::secret::SecretSource;
+
+// Bulls and Cows (Mastermind와 비슷한 숫자 게임).
+// 비밀 코드는 서로 다른 숫자 N개로 이루어져 있고, 추측할 때마다
+// bulls(숫자와 자리가 모두 맞음)와 cows(숫자는 있지만 자리가 다름)의 개수를 알려준다.
+
+pub const MIN_DIGITS: usize = 1;
+// 0~9까지 서로 다른 숫자를 써야 하므로 10자리가 최대다.
+pub const MAX_DIGITS: usize = 10;
+
+// 자릿수에 따른 기본 시도 횟수. 4자리면 10번이다.
+pub fn max_attempts_for(digits: usize) -> u32 {
+    digits as u32 * 2 + 2
+}
+
+// 자릿수에 따른 기본 hint 횟수
+pub fn hints_for(digits: usize) -> u32 {
+    digits as u32 / 2
+}
+
+// 추측 한 번에 대한 결과
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Score {
+    pub bulls: usize,
+    pub cows: usize,
+}
+
+impl Score {
+    // 고른 언어로 "1 bulls, 2 cows"를 만든다.
+    pub fn message(&self, messages: &Messages) -> String {
+        messages.format(
+            "bulls.score",
+            &[("bulls", &self.bulls), ("cows", &self.cows)],
+        )
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message(&Messages::default()))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CodeError {
+    WrongLength { expected: usize, got: usize },
+    GameOver,
+}
+
+pub struct BullsCowsEngine {
+    secret: Vec<u8>,
+    attempts: u32,
+    max_attempts: Option<u32>,
+    history: Vec<(Vec<u8>, Score)>,
+    // 아직 hint로 알려주지 않은 자리들
+    hidden_positions: Vec<usize>,
+    hints_left: u32,
+    hints_used: u32,
+    won: bool,
+    lang: Lang,
+}
+
+// 비밀 코드를 만든다. 남은 숫자 중에서 하나씩 골라 빼는 방식이라 항상 서로 다른 숫자가 나온다.
+// SecretSource를 사용하므로 --seed를 주면 같은 코드가 나온다.
+pub fn generate_code(source: &mut dyn SecretSource, digits: usize) -> Vec<u8> {
+    let mut remaining: Vec<u8> = (0..10).collect();
+    (0..digits)
+        .map(|_| {
+            let index = source.next_secret(0, remaining.len() as u32 - 1) as usize;
+            remaining.remove(index)
+        })
+        .collect()
+}
+
+// [1, 2, 3, 4] -> "1234"
+pub fn format_code(code: &[u8]) -> String {
+    code.iter().map(|digit| char::from(b'0' + digit)).collect()
+}
+
+impl BullsCowsEngine {
+    pub fn new(secret: Vec<u8>) -> BullsCowsEngine {
+        if secret.len() < MIN_DIGITS || secret.len() > MAX_DIGITS {
+            panic!(
+                "Code must have between {} and {} digits, got {}.",
+                MIN_DIGITS,
+                MAX_DIGITS,
+                secret.len()
+            );
+        }
+        let hidden_positions = (0..secret.len()).collect();
+        BullsCowsEngine {
+            secret,
+            attempts: 0,
+            max_attempts: None,
+            history: Vec::new(),
+            hidden_positions,
+            hints_left: 0,
+            hints_used: 0,
+            won: false,
+            lang: Lang::default(),
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> BullsCowsEngine {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    pub fn with_hints(mut self, hints: u32) -> BullsCowsEngine {
+        self.hints_left = hints;
+        self
+    }
+
+    pub fn with_lang(mut self, lang: Lang) -> BullsCowsEngine {
+        self.lang = lang;
+        self
+    }
+
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    pub fn guess(&mut self, guess: &[u8]) -> Result<Score, CodeError> {
+        if self.is_over() {
+            return Err(CodeError::GameOver);
+        }
+        if guess.len() != self.secret.len() {
+            return Err(CodeError::WrongLength {
+                expected: self.secret.len(),
+                got: guess.len(),
+            });
+        }
+
+        self.attempts += 1;
+        let bulls = guess
+            .iter()
+            .zip(&self.secret)
+            .filter(|(g, s)| g == s)
+            .count();
+        let common = guess.iter().filter(|g| self.secret.contains(g)).count();
+        let score = Score {
+            bulls,
+            cows: common - bulls,
+        };
+        self.history.push((guess.to_vec(), score));
+        if bulls == self.secret.len() {
+            self.won = true;
+        }
+        Ok(score)
+    }
+
+    // 아직 알려주지 않은 자리 중 가장 앞자리의 숫자를 알려준다. (자리, 숫자)
+    pub fn use_hint(&mut self) -> Option<(usize, u8)> {
+        if self.is_over() || self.hints_left == 0 || self.hidden_positions.is_empty() {
+            return None;
+        }
+        self.hints_left -= 1;
+        self.hints_used += 1;
+        let position = self.hidden_positions.remove(0);
+        Some((position, self.secret[position]))
+    }
+
+    // 추측하지 못하고 지나간 차례. 시도 횟수만 하나 쓴다.
+    pub fn skip_turn(&mut self) -> Result<(), CodeError> {
+        if self.is_over() {
+            return Err(CodeError::GameOver);
+        }
+        self.attempts += 1;
+        Ok(())
+    }
+
+    pub fn digits(&self) -> usize {
+        self.secret.len()
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    pub fn attempts_left(&self) -> Option<u32> {
+        self.max_attempts
+            .map(|max| max.saturating_sub(self.attempts))
+    }
+
+    pub fn history(&self) -> &[(Vec<u8>, Score)] {
+        &self.history
+    }
+
+    pub fn hints_left(&self) -> u32 {
+        self.hints_left
+    }
+
+    pub fn hints_used(&self) -> u32 {
+        self.hints_used
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.won
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.won || self.attempts_left() == Some(0)
+    }
+
+    pub fn reveal(&self) -> Option<String> {
+        if self.is_over() {
+            Some(format_code(&self.secret))
+        } else {
+            None
+        }
+    }
+}
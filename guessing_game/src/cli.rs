@@ -0,0 +1,406 @@
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use errors::config::{Config, ConfigError, Origin, Value};
+
+use crate::bulls;
+
+use crate::difficulty::Difficulty;
+use crate::messages::Lang;
+use crate::settings::Settings;
+use crate::solver::StrategyKind;
+
+// 첫번째 인자로 주는 서브커맨드. 아무것도 없으면 평범하게 게임을 한다.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Mode {
+    #[default]
+    Play,
+    // bench: 봇끼리 여러 판을 돌려서 통계를 낸다.
+    Bench,
+    // serve: TCP로 여러 명이 같이 하는 서버를 띄운다.
+    Serve,
+    // connect: serve로 띄운 서버에 접속한다.
+    Connect,
+    // replay <file>: 기록해둔 세션을 다시 돌려서 결과가 같은지 확인한다.
+    Replay,
+}
+
+// --mode로 고르는 게임 종류
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum GameMode {
+    // 원래의 숫자 맞추기
+    #[default]
+    Number,
+    BullsCows,
+    // 사람이 생각한 숫자를 컴퓨터가 맞춘다.
+    Reverse,
+    // 단어 맞추기
+    Hangman,
+}
+
+impl fmt::Display for GameMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameMode::Number => write!(f, "number"),
+            GameMode::BullsCows => write!(f, "bulls-cows"),
+            GameMode::Reverse => write!(f, "reverse"),
+            GameMode::Hangman => write!(f, "hangman"),
+        }
+    }
+}
+
+impl FromStr for GameMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<GameMode, String> {
+        match s {
+            "number" => Ok(GameMode::Number),
+            "bulls-cows" => Ok(GameMode::BullsCows),
+            "reverse" => Ok(GameMode::Reverse),
+            "hangman" => Ok(GameMode::Hangman),
+            other => Err(format!(
+                "unknown mode: {} (expected number, bulls-cows, reverse or hangman)",
+                other
+            )),
+        }
+    }
+}
+
+// 커맨드라인 인자를 파싱한다.
+// std::env::args()의 첫번째 값(프로그램 이름)을 제외한 나머지를 넘겨받는다.
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    pub mode: Mode,
+    pub seed: Option<u64>,
+    pub game: GameMode,
+    // bulls and cows의 자릿수
+    pub digits: Option<usize>,
+    pub difficulty: Difficulty,
+    // 점수표에 남길 이름. 없으면 USER 환경변수를 사용한다.
+    pub name: Option<String>,
+    // --scores: 게임을 하지 않고 점수표만 보여준다.
+    pub show_scores: bool,
+    pub scores_file: Option<PathBuf>,
+    // hint 명령을 쓸 수 있는 횟수. 없으면 난이도의 기본값을 사용한다.
+    pub hints: Option<u32>,
+    pub hot_cold: bool,
+    // bench에서 사용하는 옵션들
+    pub games: Option<u32>,
+    pub strategy: StrategyKind,
+    // serve, connect에서 사용하는 옵션들
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    // --record <file>: 세션을 파일에 기록한다.
+    pub record: Option<PathBuf>,
+    // replay할 파일
+    pub replay_file: Option<PathBuf>,
+    // --words <file>: 행맨에서 사용할 단어 목록. 없으면 내장된 목록을 사용한다.
+    pub words: Option<PathBuf>,
+    // --json: JSON Lines로 입출력한다.
+    pub json: bool,
+    // --tui: 터미널 전체를 쓰는 화면으로 한다.
+    pub tui: bool,
+    // --lang: 화면에 보여줄 언어. 없으면 LANG 환경변수를 보고, 그것도 없으면 영어를 사용한다.
+    pub lang: Option<Lang>,
+    // --time-limit <초>: 추측마다 제한 시간을 둔다.
+    pub time_limit: Option<Duration>,
+    // --speedrun: 전체 시간과 추측마다 걸린 시간을 보여준다.
+    pub speedrun: bool,
+    // --players alice,bob: 한 터미널에서 돌아가며 하는 hot-seat 대전
+    pub players: Vec<String>,
+    // hot-seat 대전의 라운드 수
+    pub rounds: Option<u32>,
+    // --config <file>: 설정 파일. 없으면 settings::DEFAULT_FILE을 찾아본다.
+    pub config: Option<PathBuf>,
+}
+
+// 인자를 해석하다 난 에러
+#[derive(Debug)]
+pub enum CliError {
+    // 모르는 인자, 빠진 값, 함께 쓸 수 없는 인자 등
+    Usage(String),
+    // 설정에도 쓸 수 있는 인자(--max, --port 등)의 값이 잘못되었다.
+    // 설정 파일, 환경 변수와 같은 ConfigError로 어느 인자의 값인지 알려준다.
+    Config(ConfigError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+            CliError::Config(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+impl From<String> for CliError {
+    fn from(message: String) -> CliError {
+        CliError::Usage(message)
+    }
+}
+
+impl From<ConfigError> for CliError {
+    fn from(e: ConfigError) -> CliError {
+        CliError::Config(e)
+    }
+}
+
+impl Options {
+    pub fn parse<I>(args: I) -> Result<Options, CliError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        Options::parse_with(args, &Config::new())
+    }
+
+    // 설정 파일과 환경 변수를 쌓은 config 위에 인자를 한 층 더 쌓는다.
+    // 설정에도 쓸 수 있는 인자는 Origin::Flag로 Config에 넣어서, 세 층을 같은 규칙으로 확인한다.
+    pub fn parse_with<I>(args: I, config: &Config) -> Result<Options, CliError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let (mut options, flags) = scan(args)?;
+
+        let mut config = config.clone();
+        // --easy, --hard 등으로 난이도를 고르면 아래 층의 min, max 대신 그 난이도의 범위를 쓴다.
+        if flags.origin("difficulty").is_some() {
+            config.remove("min");
+            config.remove("max");
+        }
+        let settings = Settings::from_config(&config.merge(flags))?;
+        options.difficulty = settings.difficulty;
+        options.hints = settings.hints;
+        options.lang = settings.lang;
+        options.name = settings.name;
+        options.scores_file = settings.scores_file;
+        options.host = settings.host;
+        options.port = settings.port;
+
+        options.check()?;
+        Ok(options)
+    }
+}
+
+// 인자를 한 번 훑어서, 설정에도 쓸 수 있는 인자는 Config 층(flags)으로, 나머지는 Options로 나눈다.
+// 값의 확인은 설정 층을 모두 쌓은 뒤에 한다. config_path도 같은 함수로 --config를 찾는다.
+fn scan<I>(args: I) -> Result<(Options, Config), String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut flags = Config::new();
+    let mut args = args.into_iter().peekable();
+
+    if let Some(mode) = args.peek().and_then(|arg| parse_mode(arg)) {
+        options.mode = mode;
+        args.next();
+    }
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = next_value(&mut args, "--seed")?;
+                options.seed = Some(parse_number(&value, "--seed")?);
+            }
+            "--mode" => options.game = next_value(&mut args, "--mode")?.parse()?,
+            "--digits" => {
+                let value = next_value(&mut args, "--digits")?;
+                let digits = parse_number(&value, "--digits")?;
+                if !(bulls::MIN_DIGITS..=bulls::MAX_DIGITS).contains(&digits) {
+                    return Err(format!(
+                        "--digits must be between {} and {}, got {}",
+                        bulls::MIN_DIGITS,
+                        bulls::MAX_DIGITS,
+                        digits
+                    ));
+                }
+                options.digits = Some(digits);
+            }
+            "--easy" | "--normal" | "--hard" => {
+                set_flag(&mut flags, "difficulty", &arg, arg[2..].to_string())
+            }
+            "--scores" => options.show_scores = true,
+            "--hot-cold" => options.hot_cold = true,
+            "--games" => {
+                let value = next_value(&mut args, "--games")?;
+                options.games = Some(parse_number(&value, "--games")?);
+            }
+            "--strategy" => {
+                options.strategy = next_value(&mut args, "--strategy")?.parse()?;
+            }
+            "--json" => options.json = true,
+            "--tui" => options.tui = true,
+            "--time-limit" => {
+                let value = next_value(&mut args, "--time-limit")?;
+                let seconds: f64 = parse_number(&value, "--time-limit")?;
+                if !(seconds.is_finite() && seconds > 0.0) {
+                    return Err(format!(
+                        "--time-limit must be a positive number of seconds, got {}",
+                        value
+                    ));
+                }
+                // 유한한 값이라도 Duration에 담기지 않을 만큼 크면 from_secs_f64가 panic한다.
+                let limit = Duration::try_from_secs_f64(seconds)
+                    .map_err(|_| format!("--time-limit is too large, got {}", value))?;
+                options.time_limit = Some(limit);
+            }
+            "--speedrun" => options.speedrun = true,
+            "--players" => {
+                let value = next_value(&mut args, "--players")?;
+                options.players = parse_players(&value)?;
+            }
+            "--rounds" => {
+                let value = next_value(&mut args, "--rounds")?;
+                let rounds = parse_number(&value, "--rounds")?;
+                if rounds == 0 {
+                    return Err("--rounds must be at least 1".to_string());
+                }
+                options.rounds = Some(rounds);
+            }
+            "--record" => options.record = Some(next_value(&mut args, "--record")?.into()),
+            "--words" => options.words = Some(next_value(&mut args, "--words")?.into()),
+            "--config" => options.config = Some(next_value(&mut args, "--config")?.into()),
+            other
+                if options.mode == Mode::Replay
+                    && options.replay_file.is_none()
+                    && !other.starts_with("--") =>
+            {
+                options.replay_file = Some(other.into())
+            }
+            other => {
+                // 설정에도 쓸 수 있는 인자는 값을 확인하지 않고 그대로 Config 층에 넣는다.
+                match FLAG_KEYS.iter().find(|(flag, _)| *flag == other) {
+                    Some((flag, key)) => {
+                        let value = next_value(&mut args, flag)?;
+                        set_flag(&mut flags, key, flag, value);
+                    }
+                    None => return Err(format!("unknown argument: {}", other)),
+                }
+            }
+        }
+    }
+    Ok((options, flags))
+}
+
+impl Options {
+    // 함께 쓸 수 없는 인자들을 확인한다.
+    fn check(&self) -> Result<(), String> {
+        let options = self;
+        if options.json && options.game != GameMode::Number {
+            return Err("--json supports only the number mode".to_string());
+        }
+        if options.json && options.record.is_some() {
+            return Err("--json cannot be combined with --record".to_string());
+        }
+        // 시간은 기록에 남지 않으므로 다시 돌려도 같은 결과가 나오지 않는다.
+        let timed = options.time_limit.is_some() || options.speedrun;
+        if timed && (options.json || options.record.is_some()) {
+            return Err(
+                "--time-limit and --speedrun cannot be combined with --json or --record"
+                    .to_string(),
+            );
+        }
+        if options.rounds.is_some() && options.players.is_empty() {
+            return Err("--rounds needs --players".to_string());
+        }
+        if !options.players.is_empty() && (options.json || options.record.is_some()) {
+            return Err("--players cannot be combined with --json or --record".to_string());
+        }
+        // reverse 모드는 컴퓨터가 맞추는 쪽이라 여러 사람이 돌아가며 할 것이 없다.
+        if !options.players.is_empty() && options.game == GameMode::Reverse {
+            return Err("--players cannot be used with the reverse mode".to_string());
+        }
+        // --tui는 숫자 맞추기 엔진을 직접 그리므로 --json처럼 숫자 맞추기만 된다.
+        if options.tui && options.game != GameMode::Number {
+            return Err("--tui supports only the number mode".to_string());
+        }
+        if options.tui && (options.json || options.record.is_some() || timed) {
+            return Err(
+                "--tui cannot be combined with --json, --record, --time-limit or --speedrun"
+                    .to_string(),
+            );
+        }
+        if options.tui && !options.players.is_empty() {
+            return Err("--tui cannot be combined with --players".to_string());
+        }
+        if options.words.is_some() && options.game != GameMode::Hangman {
+            return Err("--words needs --mode hangman".to_string());
+        }
+        if options.mode == Mode::Replay && options.replay_file.is_none() {
+            return Err("replay needs a session log file".to_string());
+        }
+        Ok(())
+    }
+}
+
+// 설정을 먼저 읽어야 인자를 해석할 수 있으므로, --config만 먼저 꺼내본다.
+// parse_with와 같은 scan을 쓰므로 다른 인자의 값(--name --config)을 --config로 잘못 보지 않는다.
+pub fn config_path(args: &[String]) -> Result<Option<PathBuf>, CliError> {
+    let (options, _) = scan(args.iter().cloned())?;
+    Ok(options.config)
+}
+
+fn parse_mode(arg: &str) -> Option<Mode> {
+    match arg {
+        "bench" => Some(Mode::Bench),
+        "serve" => Some(Mode::Serve),
+        "connect" => Some(Mode::Connect),
+        "replay" => Some(Mode::Replay),
+        _ => None,
+    }
+}
+
+// "alice,bob" -> ["alice", "bob"]. 두 명 이상, 서로 다른 이름이어야 한다.
+fn parse_players(value: &str) -> Result<Vec<String>, String> {
+    let players: Vec<String> = value
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .collect();
+    if players.len() < 2 {
+        return Err(format!("--players needs at least two names, got {}", value));
+    }
+    for (i, name) in players.iter().enumerate() {
+        if name.is_empty() {
+            return Err(format!("--players has an empty name: {}", value));
+        }
+        if players[..i].contains(name) {
+            return Err(format!("--players has {} more than once", name));
+        }
+    }
+    Ok(players)
+}
+
+// 설정에도 쓸 수 있는 인자와 그 키. (--easy, --normal, --hard는 difficulty 키가 된다)
+const FLAG_KEYS: &[(&str, &str)] = &[
+    ("--difficulty", "difficulty"),
+    ("--min", "min"),
+    ("--max", "max"),
+    ("--hints", "hints"),
+    ("--lang", "lang"),
+    ("--name", "name"),
+    ("--scores-file", "scores_file"),
+    ("--host", "host"),
+    ("--port", "port"),
+];
+
+fn set_flag(flags: &mut Config, key: &str, flag: &str, value: String) {
+    let origin = Origin::Flag {
+        flag: flag.to_string(),
+    };
+    flags.set(key, Value::Text(value), origin);
+}
+
+fn next_value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{} needs a value", flag))
+}
+
+fn parse_number<T: FromStr>(value: &str, flag: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {}: {}", flag, value))
+}
@@ -0,0 +1,36 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::thread;
+
+// guessing_game serve로 띄운 서버에 접속하는 클라이언트.
+// input에서 읽은 줄은 서버로 보내고, 서버가 보낸 줄은 output에 쓴다.
+// 서버에서 오는 메시지는 언제 올지 모르므로 별도의 스레드에서 읽는다.
+pub fn connect<A, R, W>(addr: A, input: R, mut output: W) -> io::Result<()>
+where
+    A: ToSocketAddrs,
+    R: BufRead,
+    W: Write + Send + 'static,
+{
+    let mut stream = TcpStream::connect(addr)?;
+    let reader = BufReader::new(stream.try_clone()?);
+
+    let printer = thread::spawn(move || -> io::Result<()> {
+        for line in reader.lines() {
+            writeln!(output, "{}", line?)?;
+            output.flush()?;
+        }
+        Ok(())
+    });
+
+    for line in input.lines() {
+        let line = line?;
+        if writeln!(stream, "{}", line).is_err() {
+            // 서버가 먼저 연결을 끊은 경우
+            break;
+        }
+    }
+    // 더 보낼 게 없다고 알려주고, 서버가 보내는 나머지 메시지를 다 받을 때까지 기다린다.
+    let _ = stream.shutdown(Shutdown::Write);
+
+    printer.join().expect("printer thread panicked")
+}
@@ -0,0 +1,58 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+// 시간을 재는 방법을 trait로 뽑아둔다.
+// 게임에서는 실제 시계(SystemClock)를, 테스트에서는 손으로 돌리는 시계(ManualClock)를 넣는다.
+// 덕분에 제한 시간 테스트를 실제로 sleep하지 않고 돌릴 수 있다.
+pub trait Clock {
+    // 시계를 만든 뒤로 지난 시간. 항상 늘어나기만 한다.
+    fn now(&self) -> Duration;
+}
+
+pub struct SystemClock {
+    started: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock {
+            started: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+// advance를 불러야만 시간이 간다.
+// clone한 값들은 같은 시간을 공유하므로, 하나는 게임에 넘기고 하나로 시간을 돌리면 된다.
+#[derive(Debug, Default, Clone)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+use std::str::FromStr;
+
+// 비밀 숫자와 추측이 들어갈 수 있는 범위. min, max 모두 포함한다.
+// std::ops::Range와 헷갈리지 않도록 GuessRange라고 이름 붙였다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuessRange {
+    min: u32,
+    max: u32,
+}
+
+impl GuessRange {
+    // min이 max보다 작지 않으면 게임이 성립하지 않으므로 Err를 반환한다.
+    pub fn new(min: u32, max: u32) -> Result<GuessRange, String> {
+        if min >= max {
+            return Err(format!(
+                "range minimum must be less than maximum, got {}..={}",
+                min, max
+            ));
+        }
+        Ok(GuessRange { min, max })
+    }
+
+    pub fn min(&self) -> u32 {
+        self.min
+    }
+
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    pub fn contains(&self, value: u32) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    // 범위 안에 있는 숫자의 개수
+    pub fn size(&self) -> u64 {
+        u64::from(self.max) - u64::from(self.min) + 1
+    }
+
+    // 이진 탐색으로 어떤 숫자든 맞추는 데 필요한 최대 시도 횟수 (ceil(log2(size + 1)))
+    pub fn binary_search_steps(&self) -> u32 {
+        64 - self.size().leading_zeros()
+    }
+}
+
+impl fmt::Display for GuessRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} and {}", self.min, self.max)
+    }
+}
+
+// 난이도 프리셋. Custom은 사용자가 직접 범위를 정한 경우다.
+// 아무것도 지정하지 않으면 원래 게임과 같은 Normal(1~100)이 된다.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Custom(GuessRange),
+}
+
+impl Difficulty {
+    pub fn range(&self) -> GuessRange {
+        match self {
+            Difficulty::Easy => GuessRange { min: 1, max: 10 },
+            Difficulty::Normal => GuessRange { min: 1, max: 100 },
+            Difficulty::Hard => GuessRange {
+                min: 1,
+                max: 10_000,
+            },
+            Difficulty::Custom(range) => *range,
+        }
+    }
+
+    // 난이도별로 주어지는 시도 횟수.
+    // 이진 탐색으로 반드시 맞출 수 있는 횟수보다 조금 여유 있게 잡았다.
+    pub fn max_attempts(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 5,
+            Difficulty::Normal => 10,
+            Difficulty::Hard => 16,
+            Difficulty::Custom(range) => range.binary_search_steps() + 3,
+        }
+    }
+
+    // hint 명령을 쓸 수 있는 기본 횟수
+    pub fn hints(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Hard | Difficulty::Custom(_) => 3,
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "easy"),
+            Difficulty::Normal => write!(f, "normal"),
+            Difficulty::Hard => write!(f, "hard"),
+            Difficulty::Custom(range) => write!(f, "custom({}-{})", range.min, range.max),
+        }
+    }
+}
+
+// "easy".parse::<Difficulty>() 처럼 문자열에서 바로 만들 수 있게 FromStr을 구현한다.
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Difficulty, String> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            other => Err(format!(
+                "unknown difficulty: {} (expected easy, normal or hard)",
+                other
+            )),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+// Ordering은 Less, Greater, Equal을 가지는 enum이다.
+use std::ops::RangeInclusive;
+
+use crate::difficulty::GuessRange;
+use crate::hint::{HintError, Proximity, Trend};
+use crate::messages::Lang;
+
+// 추측을 받아들일 수 없는 이유.
+#[derive(Debug, PartialEq)]
+pub enum GuessError {
+    // 범위를 벗어난 추측. 비교하지 않고 그대로 돌려준다.
+    OutOfRange { guess: u32, range: GuessRange },
+    // 이미 맞췄거나 시도 횟수를 다 써버린 경우
+    GameOver,
+}
+
+// 게임 한 판의 상태를 가지고 있는 구조체.
+// 입출력(stdin, stdout)과 분리해두면 비교 로직만 따로 테스트하거나 다른 곳에 붙여 쓸 수 있다.
+pub struct GameEngine {
+    secret_number: u32,
+    range: GuessRange,
+    attempts: u32,
+    max_attempts: Option<u32>,
+    // 지금까지의 추측과 그 결과
+    history: Vec<(u32, Ordering)>,
+    // hint를 쓸 때마다 좁혀지는 범위
+    hint_bounds: (u32, u32),
+    hints_left: u32,
+    hints_used: u32,
+    hot_cold: bool,
+    won: bool,
+    lang: Lang,
+}
+
+impl GameEngine {
+    pub fn new(secret_number: u32, range: GuessRange) -> GameEngine {
+        if !range.contains(secret_number) {
+            panic!(
+                "Secret number must be between {}, got {}.",
+                range, secret_number
+            );
+        }
+        GameEngine {
+            secret_number,
+            range,
+            attempts: 0,
+            max_attempts: None,
+            history: Vec::new(),
+            hint_bounds: (range.min(), range.max()),
+            hints_left: 0,
+            hints_used: 0,
+            hot_cold: false,
+            won: false,
+            lang: Lang::default(),
+        }
+    }
+
+    // 시도 횟수 제한을 건다. 제한이 없으면 맞출 때까지 계속된다.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> GameEngine {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    // hint 명령을 쓸 수 있는 횟수를 정한다. 기본값은 0이다.
+    pub fn with_hints(mut self, hints: u32) -> GameEngine {
+        self.hints_left = hints;
+        self
+    }
+
+    // Too small/Too big 외에 얼마나 가까운지(hot/cold)도 알려주는 모드.
+    // 정보를 더 주는 만큼 점수 계산에 반영된다.
+    pub fn with_hot_cold(mut self) -> GameEngine {
+        self.hot_cold = true;
+        self
+    }
+
+    // 화면에 보여줄 언어를 정한다. 기본값은 영어다.
+    pub fn with_lang(mut self, lang: Lang) -> GameEngine {
+        self.lang = lang;
+        self
+    }
+
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    // 추측 한 번을 기록하고, 비밀 숫자와 비교한 결과를 반환한다.
+    // 범위를 벗어난 추측은 시도 횟수에 포함하지 않고 Err로 알려준다.
+    // cmp 메서드를 실행하면, Ordering enum 중 하나의 값이 나온다.
+    pub fn guess(&mut self, guess: u32) -> Result<Ordering, GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        if !self.range.contains(guess) {
+            return Err(GuessError::OutOfRange {
+                guess,
+                range: self.range,
+            });
+        }
+
+        self.attempts += 1;
+        let ordering = guess.cmp(&self.secret_number);
+        self.history.push((guess, ordering));
+        if ordering == Ordering::Equal {
+            self.won = true;
+        }
+        Ok(ordering)
+    }
+
+    // 추측하지 못하고 지나간 차례 (예: 제한 시간 초과). 비교 없이 시도 횟수만 하나 쓴다.
+    pub fn skip_turn(&mut self) -> Result<(), GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        self.attempts += 1;
+        Ok(())
+    }
+
+    pub fn range(&self) -> GuessRange {
+        self.range
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    // 제한이 없으면 None
+    pub fn attempts_left(&self) -> Option<u32> {
+        self.max_attempts
+            .map(|max| max.saturating_sub(self.attempts))
+    }
+
+    pub fn history(&self) -> &[(u32, Ordering)] {
+        &self.history
+    }
+
+    // 지금까지 받은 피드백으로 봤을 때 비밀 숫자가 있을 수 있는 범위.
+    // Too small이었던 추측들보다는 크고, Too big이었던 추측들보다는 작다.
+    // hint로 좁혀진 범위도 함께 반영한다.
+    pub fn plausible_range(&self) -> RangeInclusive<u32> {
+        let (mut low, mut high) = self.hint_bounds;
+        for &(guess, ordering) in &self.history {
+            match ordering {
+                Ordering::Less => low = low.max(guess + 1),
+                Ordering::Greater => high = high.min(guess - 1),
+                Ordering::Equal => return guess..=guess,
+            }
+        }
+        low..=high
+    }
+
+    // 남은 후보를 반으로 나눠서, 비밀 숫자가 있는 쪽 절반을 알려준다.
+    pub fn use_hint(&mut self) -> Result<RangeInclusive<u32>, HintError> {
+        if self.is_over() {
+            return Err(HintError::GameOver);
+        }
+        if self.hints_left == 0 {
+            return Err(HintError::NoHintsLeft);
+        }
+        self.hints_left -= 1;
+        self.hints_used += 1;
+
+        let plausible = self.plausible_range();
+        let (low, high) = (*plausible.start(), *plausible.end());
+        let middle = low + (high - low) / 2;
+        self.hint_bounds = if self.secret_number <= middle {
+            (low, middle)
+        } else {
+            (middle + 1, high)
+        };
+        Ok(self.plausible_range())
+    }
+
+    pub fn hints_left(&self) -> u32 {
+        self.hints_left
+    }
+
+    pub fn hints_used(&self) -> u32 {
+        self.hints_used
+    }
+
+    pub fn is_hot_cold(&self) -> bool {
+        self.hot_cold
+    }
+
+    // 추측이 비밀 숫자에 얼마나 가까운지
+    pub fn proximity(&self, guess: u32) -> Proximity {
+        Proximity::between(guess, self.secret_number, self.range)
+    }
+
+    // 마지막 추측이 그 전 추측보다 가까워졌는지. 추측이 두 번 이상 있어야 알 수 있다.
+    pub fn trend(&self) -> Option<Trend> {
+        let len = self.history.len();
+        if len < 2 {
+            return None;
+        }
+        let distance = |guess: u32| guess.max(self.secret_number) - guess.min(self.secret_number);
+        let last = distance(self.history[len - 1].0);
+        let previous = distance(self.history[len - 2].0);
+        Some(match last.cmp(&previous) {
+            Ordering::Less => Trend::Warmer,
+            Ordering::Greater => Trend::Colder,
+            Ordering::Equal => Trend::Same,
+        })
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.won
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.won || self.attempts_left() == Some(0)
+    }
+
+    // 게임이 끝나기 전에는 비밀 숫자를 알려주지 않는다.
+    pub fn reveal(&self) -> Option<u32> {
+        if self.is_over() {
+            Some(self.secret_number)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,821 @@
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use crate::bulls::{format_code, BullsCowsEngine, CodeError};
+use crate::engine::{GameEngine, GuessError};
+use crate::hangman::{HangmanEngine, HangmanError, HangmanGuess, LetterResult};
+use crate::hint::HintError;
+use crate::input::{
+    parse_code, parse_command, parse_hangman, parse_input, parse_reply, Command, Input,
+};
+use crate::messages::Messages;
+use crate::reverse::{Bound, Guesser};
+use crate::score::Performance;
+
+// 한 판이 어떻게 끝났는지를 나타낸다.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Won { attempts: u32 },
+    // 시도 횟수를 다 써버린 경우. secret은 공개된 정답이다.
+    Lost { attempts: u32, secret: String },
+    // 입력이 끝났거나(EOF) quit을 입력해서 그만둔 경우
+    Abandoned { attempts: u32 },
+    // reverse 모드에서 사람의 대답들이 서로 맞지 않는 경우
+    Cheated { attempts: u32 },
+}
+
+// 숫자 맞추기와 bulls and cows가 같은 입력 루프를 쓸 수 있도록 게임마다 다른 부분만 trait로 뽑았다.
+// quit/hint/history 명령과 EOF 처리는 play_game에서 공통으로 한다.
+pub trait Game {
+    fn write_intro(&self, output: &mut dyn Write) -> io::Result<()>;
+
+    // 명령어가 아닌 한 줄을 처리한다. 게임이 끝났으면 Some(Outcome)을 반환한다.
+    fn submit(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<Outcome>>;
+
+    fn write_hint(&mut self, output: &mut dyn Write) -> io::Result<()>;
+
+    fn write_history(&self, output: &mut dyn Write) -> io::Result<()>;
+
+    // 추측 없이 차례를 넘긴다. (제한 시간 초과) 시도 횟수를 다 썼으면 Some(Outcome)을 반환한다.
+    fn skip_turn(&mut self, output: &mut dyn Write) -> io::Result<Option<Outcome>>;
+
+    // 입력을 받기 전에 보여줄 문장의 키. 대부분의 게임은 "prompt"를 그대로 쓴다.
+    fn prompt_key(&self) -> &'static str {
+        "prompt"
+    }
+
+    fn attempts(&self) -> u32;
+
+    // 마지막으로 받아들인 추측을 게임에 맞는 형태의 문자열로 돌려준다.
+    fn last_guess(&self) -> Option<String>;
+
+    // 점수 계산에 필요한 기록
+    fn performance(&self, elapsed: Duration) -> Performance;
+
+    // 화면에 보여줄 문장을 찾을 메시지 카탈로그
+    fn messages(&self) -> Messages;
+}
+
+// stdin/stdout 대신 BufRead와 Write를 구현한 아무 타입이나 받는다.
+// 덕분에 테스트에서는 &[u8]과 Vec<u8>로 스크립트된 게임을 돌려볼 수 있다.
+// ?Sized를 붙여서 Box<dyn Game> 안의 게임도 넘길 수 있게 했다.
+pub fn play_game<G: Game + ?Sized, R: BufRead, W: Write>(
+    game: &mut G,
+    input: R,
+    output: W,
+) -> io::Result<Outcome> {
+    play_with(game, input, output, |_, _, _| Ok(()))
+}
+
+// play_game과 같지만, 한 줄을 처리할 때마다 (게임, 입력한 줄, 그 줄에 대한 출력)을 on_step에 넘겨준다.
+// 세션 기록(replay)처럼 게임 진행을 옆에서 지켜봐야 할 때 사용한다.
+pub fn play_with<G, R, W, F>(
+    game: &mut G,
+    mut input: R,
+    mut output: W,
+    mut on_step: F,
+) -> io::Result<Outcome>
+where
+    G: Game + ?Sized,
+    R: BufRead,
+    W: Write,
+    F: FnMut(&G, &str, &[u8]) -> io::Result<()>,
+{
+    game.write_intro(&mut output)?;
+
+    loop {
+        writeln!(output, "{}", game.messages().get(game.prompt_key()))?;
+
+        let mut line = String::new(); // mutable
+
+        // read_line은 읽은 바이트 수를 반환한다. 0이면 더 이상 읽을 입력이 없다는 뜻이다.
+        // ?는 에러가 나면 호출한 쪽으로 io::Error를 그대로 넘겨준다.
+        if input.read_line(&mut line)? == 0 {
+            return Ok(Outcome::Abandoned {
+                attempts: game.attempts(),
+            });
+        }
+
+        let mut feedback = Vec::new();
+        let outcome = step(game, &line, &mut feedback)?;
+        output.write_all(&feedback)?;
+        on_step(game, &line, &feedback)?;
+
+        if let Some(outcome) = outcome {
+            return Ok(outcome);
+        }
+    }
+}
+
+// 입력 한 줄을 처리한다. 공통 명령어를 먼저 확인하고, 나머지는 게임에 넘긴다.
+pub fn step<G: Game + ?Sized>(
+    game: &mut G,
+    line: &str,
+    output: &mut dyn Write,
+) -> io::Result<Option<Outcome>> {
+    match parse_command(line) {
+        Some(Command::Quit) => {
+            writeln!(output, "{}", game.messages().get("bye"))?;
+            Ok(Some(Outcome::Abandoned {
+                attempts: game.attempts(),
+            }))
+        }
+        Some(Command::Hint) => {
+            game.write_hint(output)?;
+            Ok(None)
+        }
+        Some(Command::History) => {
+            game.write_history(output)?;
+            Ok(None)
+        }
+        None => game.submit(line, output),
+    }
+}
+
+// 숫자 맞추기 게임을 한다.
+pub fn play<R: BufRead, W: Write>(
+    engine: &mut GameEngine,
+    input: R,
+    output: W,
+) -> io::Result<Outcome> {
+    play_game(engine, input, output)
+}
+
+impl Game for GameEngine {
+    fn write_intro(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        let range = self.range();
+        writeln!(output, "{}", messages.get("number.intro"))?;
+        writeln!(
+            output,
+            "{}",
+            messages.format(
+                "number.range",
+                &[("min", &range.min()), ("max", &range.max())]
+            )
+        )?;
+        write_attempts_total(&messages, self.max_attempts(), output)
+    }
+
+    fn submit(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        let messages = self.messages();
+        // 입력을 검증해서, 잘못된 경우에는 왜 잘못됐는지 알려주고 다시 입력받는다.
+        let guess = match parse_input(line, self.range()) {
+            Ok(Input::Guess(guess)) => guess,
+            // 명령어는 play_game에서 먼저 처리한다.
+            Ok(Input::Command(_)) => return Ok(None),
+            Err(e) => {
+                writeln!(output, "{}", e.message(&messages))?;
+                return Ok(None);
+            }
+        };
+
+        writeln!(
+            output,
+            "{}",
+            messages.format("you_guessed", &[("guess", &guess)])
+        )?;
+
+        match self.guess(guess) {
+            Err(GuessError::OutOfRange { range, .. }) => {
+                writeln!(
+                    output,
+                    "{}",
+                    messages.format(
+                        "error.out_of_range",
+                        &[("min", &range.min()), ("max", &range.max())]
+                    )
+                )?;
+                return Ok(None);
+            }
+            // play_game은 게임이 끝나면 바로 반환하지만, step을 직접 부르는 쪽은 끝난 게임에 입력을 넣을 수도 있다.
+            Err(GuessError::GameOver) => {
+                writeln!(output, "{}", messages.get("game_over"))?;
+                return Ok(None);
+            }
+            Ok(Ordering::Less) => {
+                writeln!(output, "{}", messages.get("number.too_small"))?;
+                write_proximity(self, guess, output)?;
+            }
+            Ok(Ordering::Greater) => {
+                writeln!(output, "{}", messages.get("number.too_big"))?;
+                write_proximity(self, guess, output)?;
+            }
+            Ok(Ordering::Equal) => {
+                writeln!(output, "{}", messages.get("win"))?;
+                return Ok(Some(Outcome::Won {
+                    attempts: self.attempts(),
+                }));
+            }
+        }
+
+        let secret = self.reveal().map(|secret_number| secret_number.to_string());
+        write_attempts_left(
+            &messages,
+            self.attempts(),
+            self.attempts_left(),
+            ("number.lost", secret),
+            output,
+        )
+    }
+
+    fn write_hint(&mut self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        match self.use_hint() {
+            Ok(narrowed) => writeln!(
+                output,
+                "{}",
+                messages.format(
+                    "number.hint",
+                    &[
+                        ("min", narrowed.start()),
+                        ("max", narrowed.end()),
+                        ("left", &self.hints_left()),
+                    ]
+                )
+            ),
+            Err(_) => writeln!(output, "{}", messages.get("no_hints_left")),
+        }
+    }
+
+    fn write_history(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        if self.history().is_empty() {
+            return writeln!(output, "{}", messages.get("no_guesses"));
+        }
+        for (i, (guess, ordering)) in self.history().iter().enumerate() {
+            let result = match ordering {
+                Ordering::Less => "number.result.too_small",
+                Ordering::Greater => "number.result.too_big",
+                Ordering::Equal => "number.result.correct",
+            };
+            writeln!(output, "{:>3}. {} ({})", i + 1, guess, messages.get(result))?;
+        }
+        Ok(())
+    }
+
+    fn skip_turn(&mut self, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        if GameEngine::skip_turn(self).is_err() {
+            return Ok(None);
+        }
+        let secret = self.reveal().map(|secret_number| secret_number.to_string());
+        write_attempts_left(
+            &self.messages(),
+            self.attempts(),
+            self.attempts_left(),
+            ("number.lost", secret),
+            output,
+        )
+    }
+
+    fn attempts(&self) -> u32 {
+        GameEngine::attempts(self)
+    }
+
+    fn last_guess(&self) -> Option<String> {
+        self.history().last().map(|(guess, _)| guess.to_string())
+    }
+
+    fn performance(&self, elapsed: Duration) -> Performance {
+        Performance::from_engine(self, elapsed)
+    }
+
+    fn messages(&self) -> Messages {
+        Messages::new(self.lang())
+    }
+}
+
+// hot/cold 모드일 때만 얼마나 가까운지, 직전보다 가까워졌는지 알려준다.
+fn write_proximity(engine: &GameEngine, guess: u32, output: &mut dyn Write) -> io::Result<()> {
+    if !engine.is_hot_cold() {
+        return Ok(());
+    }
+    let messages = engine.messages();
+    let proximity = messages.get(engine.proximity(guess).key());
+    match engine.trend() {
+        Some(trend) => writeln!(output, "{}! ({})", proximity, messages.get(trend.key())),
+        None => writeln!(output, "{}!", proximity),
+    }
+}
+
+fn write_attempts_total(
+    messages: &Messages,
+    max_attempts: Option<u32>,
+    output: &mut dyn Write,
+) -> io::Result<()> {
+    match max_attempts {
+        Some(max_attempts) => writeln!(
+            output,
+            "{}",
+            messages.format("attempts_total", &[("attempts", &max_attempts)])
+        ),
+        None => Ok(()),
+    }
+}
+
+// 틀린 추측 다음에 남은 시도 횟수를 알려주고, 다 썼으면 정답을 공개하며 끝낸다.
+// secret은 (졌을 때 보여줄 메시지의 키, 공개된 정답)이다.
+fn write_attempts_left(
+    messages: &Messages,
+    attempts: u32,
+    attempts_left: Option<u32>,
+    (lost_key, secret): (&'static str, Option<String>),
+    output: &mut dyn Write,
+) -> io::Result<Option<Outcome>> {
+    match attempts_left {
+        Some(0) => {
+            let secret = secret.expect("secret is revealed once the game is over");
+            writeln!(
+                output,
+                "{}",
+                messages.format(lost_key, &[("secret", &secret)])
+            )?;
+            Ok(Some(Outcome::Lost { attempts, secret }))
+        }
+        Some(left) => {
+            writeln!(
+                output,
+                "{}",
+                messages.format("attempts_left", &[("left", &left)])
+            )?;
+            Ok(None)
+        }
+        None => Ok(None),
+    }
+}
+
+impl Game for BullsCowsEngine {
+    fn write_intro(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        writeln!(output, "{}", messages.get("bulls.intro"))?;
+        writeln!(
+            output,
+            "{}",
+            messages.format("bulls.rules", &[("digits", &self.digits())])
+        )?;
+        writeln!(output, "{}", messages.get("bulls.explain"))?;
+        write_attempts_total(&messages, self.max_attempts(), output)
+    }
+
+    fn submit(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        let messages = self.messages();
+        let code = match parse_code(line, self.digits()) {
+            Ok(Input::Guess(code)) => code,
+            Ok(Input::Command(_)) => return Ok(None),
+            Err(e) => {
+                writeln!(output, "{}", e.message(&messages))?;
+                return Ok(None);
+            }
+        };
+
+        writeln!(
+            output,
+            "{}",
+            messages.format("you_guessed", &[("guess", &format_code(&code))])
+        )?;
+
+        match self.guess(&code) {
+            Ok(score) if score.bulls == self.digits() => {
+                writeln!(output, "{}", messages.get("win"))?;
+                return Ok(Some(Outcome::Won {
+                    attempts: self.attempts(),
+                }));
+            }
+            Ok(score) => writeln!(output, "{}", score.message(&messages))?,
+            // parse_code가 자릿수를 확인하므로 보통은 일어나지 않지만, panic하지 않고 다시 입력받는다.
+            Err(CodeError::WrongLength { expected, got }) => {
+                writeln!(
+                    output,
+                    "{}",
+                    messages.format(
+                        "error.wrong_length",
+                        &[("expected", &expected), ("got", &got)]
+                    )
+                )?;
+                return Ok(None);
+            }
+            Err(CodeError::GameOver) => {
+                writeln!(output, "{}", messages.get("game_over"))?;
+                return Ok(None);
+            }
+        }
+
+        write_attempts_left(
+            &messages,
+            self.attempts(),
+            self.attempts_left(),
+            ("bulls.lost", self.reveal()),
+            output,
+        )
+    }
+
+    fn write_hint(&mut self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        match self.use_hint() {
+            Some((position, digit)) => writeln!(
+                output,
+                "{}",
+                messages.format(
+                    "bulls.hint",
+                    &[
+                        ("position", &(position + 1)),
+                        ("digit", &digit),
+                        ("left", &self.hints_left()),
+                    ]
+                )
+            ),
+            None => writeln!(output, "{}", messages.get("no_hints_left")),
+        }
+    }
+
+    fn write_history(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        if self.history().is_empty() {
+            return writeln!(output, "{}", messages.get("no_guesses"));
+        }
+        for (i, (code, score)) in self.history().iter().enumerate() {
+            writeln!(
+                output,
+                "{:>3}. {} ({})",
+                i + 1,
+                format_code(code),
+                score.message(&messages)
+            )?;
+        }
+        Ok(())
+    }
+
+    fn skip_turn(&mut self, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        if BullsCowsEngine::skip_turn(self).is_err() {
+            return Ok(None);
+        }
+        write_attempts_left(
+            &self.messages(),
+            self.attempts(),
+            self.attempts_left(),
+            ("bulls.lost", self.reveal()),
+            output,
+        )
+    }
+
+    fn attempts(&self) -> u32 {
+        BullsCowsEngine::attempts(self)
+    }
+
+    fn last_guess(&self) -> Option<String> {
+        self.history().last().map(|(code, _)| format_code(code))
+    }
+
+    fn performance(&self, elapsed: Duration) -> Performance {
+        Performance::from_bulls_cows(self, elapsed)
+    }
+
+    fn messages(&self) -> Messages {
+        Messages::new(self.lang())
+    }
+}
+
+impl Game for Guesser {
+    fn write_intro(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        let range = self.range();
+        writeln!(
+            output,
+            "{}",
+            messages.format(
+                "reverse.intro",
+                &[("min", &range.min()), ("max", &range.max())]
+            )
+        )?;
+        writeln!(output, "{}", messages.get("reverse.rules"))?;
+        if let Some(guess) = self.current_guess() {
+            writeln!(
+                output,
+                "{}",
+                messages.format("reverse.guess", &[("guess", &guess)])
+            )?;
+        }
+        Ok(())
+    }
+
+    fn submit(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        let messages = self.messages();
+        // 맞춘 뒤(또는 거짓말이 드러난 뒤)에는 물어볼 추측이 없다.
+        let guess = match self.current_guess() {
+            Some(guess) => guess,
+            None => {
+                writeln!(output, "{}", messages.get("game_over"))?;
+                return Ok(None);
+            }
+        };
+        let reply = match parse_reply(line) {
+            Ok(Input::Guess(reply)) => reply,
+            Ok(Input::Command(_)) => return Ok(None),
+            Err(e) => {
+                writeln!(output, "{}", e.message(&messages))?;
+                return Ok(None);
+            }
+        };
+
+        match self.answer(reply) {
+            Ok(Some(next)) => {
+                writeln!(
+                    output,
+                    "{}",
+                    messages.format("reverse.guess", &[("guess", &next)])
+                )?;
+                Ok(None)
+            }
+            Ok(None) => {
+                writeln!(
+                    output,
+                    "{}",
+                    messages.format(
+                        "reverse.found",
+                        &[("guess", &guess), ("attempts", &self.attempts())]
+                    )
+                )?;
+                Ok(Some(Outcome::Won {
+                    attempts: self.attempts(),
+                }))
+            }
+            Err(contradiction) => {
+                // 부딪히는 두 대답(또는 처음 정한 범위)을 보여준다.
+                writeln!(output, "{}", messages.get("reverse.cheating"))?;
+                for (bound, key) in [
+                    (contradiction.above, "reverse.answer_higher"),
+                    (contradiction.below, "reverse.answer_lower"),
+                ] {
+                    if let Bound::Answer { step, guess } = bound {
+                        writeln!(
+                            output,
+                            "{}",
+                            messages.format(key, &[("step", &step), ("guess", &guess)])
+                        )?;
+                    }
+                }
+                if let (Bound::Range(_), _) | (_, Bound::Range(_)) =
+                    (contradiction.above, contradiction.below)
+                {
+                    let range = self.range();
+                    writeln!(
+                        output,
+                        "{}",
+                        messages.format(
+                            "reverse.range",
+                            &[("min", &range.min()), ("max", &range.max())]
+                        )
+                    )?;
+                }
+                Ok(Some(Outcome::Cheated {
+                    attempts: self.attempts(),
+                }))
+            }
+        }
+    }
+
+    fn write_hint(&mut self, output: &mut dyn Write) -> io::Result<()> {
+        writeln!(output, "{}", self.messages().get("reverse.no_hints"))
+    }
+
+    fn write_history(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        if self.history().is_empty() {
+            return writeln!(output, "{}", messages.get("no_guesses"));
+        }
+        for (i, (guess, reply)) in self.history().iter().enumerate() {
+            let result = match reply {
+                Ordering::Greater => "reverse.result.higher",
+                Ordering::Less => "reverse.result.lower",
+                Ordering::Equal => "reverse.result.correct",
+            };
+            writeln!(output, "{:>3}. {} ({})", i + 1, guess, messages.get(result))?;
+        }
+        Ok(())
+    }
+
+    // 대답이 늦었다고 컴퓨터의 기회를 뺏을 이유는 없으므로 아무것도 하지 않는다.
+    fn skip_turn(&mut self, _output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        Ok(None)
+    }
+
+    fn prompt_key(&self) -> &'static str {
+        "reverse.prompt"
+    }
+
+    fn attempts(&self) -> u32 {
+        Guesser::attempts(self)
+    }
+
+    fn last_guess(&self) -> Option<String> {
+        self.history().last().map(|(guess, _)| guess.to_string())
+    }
+
+    // 맞추는 쪽이 컴퓨터라서 사람의 점수는 없다.
+    fn performance(&self, elapsed: Duration) -> Performance {
+        Performance {
+            base: 0,
+            attempts: self.attempts(),
+            max_attempts: self.attempts(),
+            hints_used: 0,
+            hot_cold: false,
+            elapsed,
+        }
+    }
+
+    fn messages(&self) -> Messages {
+        Messages::new(self.lang())
+    }
+}
+
+impl Game for HangmanEngine {
+    fn write_intro(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        writeln!(output, "{}", messages.get("hangman.intro"))?;
+        writeln!(
+            output,
+            "{}",
+            messages.format("hangman.rules", &[("lives", &self.max_lives())])
+        )?;
+        writeln!(
+            output,
+            "{}",
+            messages.format("hangman.word", &[("word", &self.masked())])
+        )
+    }
+
+    fn submit(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        let messages = self.messages();
+        let guess = match parse_hangman(line) {
+            Ok(Input::Guess(guess)) => guess,
+            Ok(Input::Command(_)) => return Ok(None),
+            Err(e) => {
+                writeln!(output, "{}", e.message(&messages))?;
+                return Ok(None);
+            }
+        };
+
+        match guess {
+            HangmanGuess::Letter(letter) => match self.guess_letter(letter) {
+                Ok(LetterResult::Hit(_)) => writeln!(
+                    output,
+                    "{}",
+                    messages.format("hangman.hit", &[("letter", &letter)])
+                )?,
+                Ok(LetterResult::Miss) => writeln!(
+                    output,
+                    "{}",
+                    messages.format("hangman.miss", &[("letter", &letter)])
+                )?,
+                // 이미 입력한 글자는 목숨을 깎지 않고 다시 입력받는다.
+                Err(HangmanError::AlreadyUsed(letter)) => {
+                    writeln!(
+                        output,
+                        "{}",
+                        messages.format("hangman.already_used", &[("letter", &letter)])
+                    )?;
+                    return Ok(None);
+                }
+                Err(HangmanError::GameOver) => {
+                    writeln!(output, "{}", messages.get("game_over"))?;
+                    return Ok(None);
+                }
+            },
+            HangmanGuess::Word(word) => match self.guess_word(&word) {
+                Ok(true) => {}
+                Ok(false) => writeln!(
+                    output,
+                    "{}",
+                    messages.format("hangman.wrong_word", &[("word", &word)])
+                )?,
+                // 단어 추측에는 이미 입력한 글자라는 에러가 없으므로 끝난 게임만 남는다.
+                Err(_) => {
+                    writeln!(output, "{}", messages.get("game_over"))?;
+                    return Ok(None);
+                }
+            },
+        }
+
+        write_hangman_state(self, output)
+    }
+
+    fn write_hint(&mut self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        match self.use_hint() {
+            Ok(letter) => {
+                writeln!(
+                    output,
+                    "{}",
+                    messages.format(
+                        "hangman.hint",
+                        &[("letter", &letter), ("left", &self.hints_left())]
+                    )
+                )?;
+                writeln!(
+                    output,
+                    "{}",
+                    messages.format("hangman.word", &[("word", &self.masked())])
+                )
+            }
+            Err(HintError::NoHintsLeft) => writeln!(output, "{}", messages.get("no_hints_left")),
+            Err(HintError::WouldSolve) => {
+                writeln!(output, "{}", messages.get("hangman.hint_would_solve"))
+            }
+            Err(HintError::GameOver) => writeln!(output, "{}", messages.get("game_over")),
+        }
+    }
+
+    fn write_history(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        if self.history().is_empty() {
+            return writeln!(output, "{}", messages.get("no_guesses"));
+        }
+        for (i, (guess, hit)) in self.history().iter().enumerate() {
+            let result = if *hit {
+                "hangman.result.hit"
+            } else {
+                "hangman.result.miss"
+            };
+            writeln!(output, "{:>3}. {} ({})", i + 1, guess, messages.get(result))?;
+        }
+        Ok(())
+    }
+
+    fn skip_turn(&mut self, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        if HangmanEngine::skip_turn(self).is_err() {
+            return Ok(None);
+        }
+        write_hangman_state(self, output)
+    }
+
+    fn prompt_key(&self) -> &'static str {
+        "hangman.prompt"
+    }
+
+    fn attempts(&self) -> u32 {
+        HangmanEngine::attempts(self)
+    }
+
+    fn last_guess(&self) -> Option<String> {
+        self.history().last().map(|(guess, _)| guess.clone())
+    }
+
+    fn performance(&self, elapsed: Duration) -> Performance {
+        Performance::from_hangman(self, elapsed)
+    }
+
+    fn messages(&self) -> Messages {
+        Messages::new(self.lang())
+    }
+}
+
+// 추측 다음에 가려진 단어, 사용한 글자, 남은 목숨을 보여주고 게임이 끝났는지 확인한다.
+fn write_hangman_state(
+    engine: &HangmanEngine,
+    output: &mut dyn Write,
+) -> io::Result<Option<Outcome>> {
+    let messages = engine.messages();
+    writeln!(
+        output,
+        "{}",
+        messages.format("hangman.word", &[("word", &engine.masked())])
+    )?;
+    if engine.is_won() {
+        writeln!(output, "{}", messages.get("win"))?;
+        return Ok(Some(Outcome::Won {
+            attempts: engine.attempts(),
+        }));
+    }
+
+    let letters: Vec<String> = engine
+        .sorted_used_letters()
+        .iter()
+        .map(char::to_string)
+        .collect();
+    writeln!(
+        output,
+        "{}",
+        messages.format("hangman.used", &[("letters", &letters.join(", "))])
+    )?;
+    if engine.lives() == 0 {
+        let secret = engine
+            .reveal()
+            .expect("the word is revealed once the game is over");
+        writeln!(
+            output,
+            "{}",
+            messages.format("hangman.lost", &[("secret", &secret)])
+        )?;
+        return Ok(Some(Outcome::Lost {
+            attempts: engine.attempts(),
+            secret,
+        }));
+    }
+    writeln!(
+        output,
+        "{}",
+        messages.format("hangman.lives", &[("lives", &engine.lives())])
+    )?;
+    Ok(None)
+}
@@ -0,0 +1,328 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+use crate::hint::HintError;
+use crate::messages::Lang;
+use crate::secret::SecretSource;
+
+// 행맨: 글자를 하나씩 맞혀서 숨겨진 단어를 완성한다. 틀릴 때마다 목숨이 하나씩 줄어든다.
+// 단어는 바이트가 아니라 char 단위로 다룬다. 그래야 "사과" 같은 한글 단어도 글자 하나하나를 맞힐 수 있다.
+// (String의 len()은 바이트 수라서 한글 한 글자가 3이 된다.)
+
+pub const DEFAULT_LIVES: u32 = 6;
+
+// --words를 주지 않았을 때 사용하는 단어들
+const WORDS_EN: &[&str] = &[
+    "ownership",
+    "borrow",
+    "lifetime",
+    "trait",
+    "generic",
+    "closure",
+    "iterator",
+    "vector",
+    "string",
+    "hashmap",
+    "module",
+    "crate",
+    "struct",
+    "pattern",
+    "compiler",
+];
+
+const WORDS_KO: &[&str] = &[
+    "소유권",
+    "빌림",
+    "라이프타임",
+    "트레이트",
+    "제네릭",
+    "클로저",
+    "반복자",
+    "벡터",
+    "문자열",
+    "해시맵",
+    "모듈",
+    "구조체",
+    "패턴",
+    "컴파일러",
+];
+
+pub fn bundled_words(lang: Lang) -> Vec<String> {
+    let words = match lang {
+        Lang::En => WORDS_EN,
+        Lang::Ko => WORDS_KO,
+    };
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+// 한 줄에 단어 하나씩 적힌 파일을 읽는다. 빈 줄과 #으로 시작하는 줄은 건너뛴다.
+pub fn load_words(path: &Path) -> io::Result<Vec<String>> {
+    let words: Vec<String> = fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect();
+    if let Some(word) = words
+        .iter()
+        .find(|word| !word.chars().any(char::is_alphabetic))
+    {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("{}: '{}' has no letters to guess", path.display(), word),
+        ));
+    }
+    if words.is_empty() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("{}: no words found", path.display()),
+        ));
+    }
+    Ok(words)
+}
+
+pub fn pick_word(source: &mut dyn SecretSource, words: &[String]) -> String {
+    let index = source.next_secret(0, words.len() as u32 - 1) as usize;
+    words[index].clone()
+}
+
+// 대소문자를 구분하지 않도록 소문자로 맞춘다.
+// 소문자가 여러 글자가 되는 특별한 경우(예: 'İ')는 그대로 둔다.
+pub fn normalize(letter: char) -> char {
+    let mut lower = letter.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => letter,
+    }
+}
+
+// 한 줄의 입력이 어떤 추측인지
+#[derive(Debug, Clone, PartialEq)]
+pub enum HangmanGuess {
+    Letter(char),
+    Word(String),
+}
+
+// 글자 하나를 추측한 결과
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LetterResult {
+    // 단어 안에 몇 번 나오는지
+    Hit(usize),
+    Miss,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HangmanError {
+    AlreadyUsed(char),
+    GameOver,
+}
+
+pub struct HangmanEngine {
+    word: Vec<char>,
+    used: HashSet<char>,
+    lives: u32,
+    max_lives: u32,
+    // 지금까지의 추측(글자 하나 또는 단어)과 맞았는지
+    history: Vec<(String, bool)>,
+    hints_left: u32,
+    hints_used: u32,
+    guessed_word: bool,
+    lang: Lang,
+}
+
+impl HangmanEngine {
+    pub fn new(word: &str) -> HangmanEngine {
+        let word: Vec<char> = word.trim().chars().map(normalize).collect();
+        if !word.iter().any(|c| c.is_alphabetic()) {
+            panic!("The word must have at least one letter.");
+        }
+        HangmanEngine {
+            word,
+            used: HashSet::new(),
+            lives: DEFAULT_LIVES,
+            max_lives: DEFAULT_LIVES,
+            history: Vec::new(),
+            hints_left: 0,
+            hints_used: 0,
+            guessed_word: false,
+            lang: Lang::default(),
+        }
+    }
+
+    pub fn with_lives(mut self, lives: u32) -> HangmanEngine {
+        self.lives = lives;
+        self.max_lives = lives;
+        self
+    }
+
+    pub fn with_hints(mut self, hints: u32) -> HangmanEngine {
+        self.hints_left = hints;
+        self
+    }
+
+    pub fn with_lang(mut self, lang: Lang) -> HangmanEngine {
+        self.lang = lang;
+        self
+    }
+
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    pub fn guess_letter(&mut self, letter: char) -> Result<LetterResult, HangmanError> {
+        if self.is_over() {
+            return Err(HangmanError::GameOver);
+        }
+        let letter = normalize(letter);
+        // insert는 이미 들어있던 값이면 false를 반환한다.
+        if !self.used.insert(letter) {
+            return Err(HangmanError::AlreadyUsed(letter));
+        }
+
+        let count = self.word.iter().filter(|&&c| c == letter).count();
+        self.history.push((letter.to_string(), count > 0));
+        if count > 0 {
+            Ok(LetterResult::Hit(count))
+        } else {
+            self.lives -= 1;
+            Ok(LetterResult::Miss)
+        }
+    }
+
+    // 단어 전체를 추측한다. 틀리면 목숨이 하나 줄어든다.
+    pub fn guess_word(&mut self, guess: &str) -> Result<bool, HangmanError> {
+        if self.is_over() {
+            return Err(HangmanError::GameOver);
+        }
+        let guess: String = guess.trim().chars().map(normalize).collect();
+        let correct = guess.chars().eq(self.word.iter().copied());
+        self.history.push((guess, correct));
+        if correct {
+            self.guessed_word = true;
+        } else {
+            self.lives -= 1;
+        }
+        Ok(correct)
+    }
+
+    // 추측하지 못하고 지나간 차례. 목숨을 하나 잃는다.
+    pub fn skip_turn(&mut self) -> Result<(), HangmanError> {
+        if self.is_over() {
+            return Err(HangmanError::GameOver);
+        }
+        self.lives -= 1;
+        Ok(())
+    }
+
+    // 아직 드러나지 않은 글자 중 처음 나오는 것을 알려주고, 사용한 글자로 표시한다.
+    // hint로 단어가 완성되어 버리면 안 되므로, 숨은 글자가 한 종류만 남았으면 알려주지 않는다.
+    pub fn use_hint(&mut self) -> Result<char, HintError> {
+        if self.is_over() {
+            return Err(HintError::GameOver);
+        }
+        if self.hints_left == 0 {
+            return Err(HintError::NoHintsLeft);
+        }
+        let hidden: Vec<char> = self
+            .word
+            .iter()
+            .copied()
+            .filter(|c| !self.is_revealed(*c))
+            .collect();
+        // 게임이 끝나지 않았다면 숨은 글자가 적어도 하나는 있다.
+        let letter = *hidden.first().ok_or(HintError::GameOver)?;
+        if hidden.iter().all(|&c| c == letter) {
+            return Err(HintError::WouldSolve);
+        }
+        self.hints_left -= 1;
+        self.hints_used += 1;
+        self.used.insert(letter);
+        Ok(letter)
+    }
+
+    // "r _ s t" 처럼 맞힌 글자만 보여준다. 글자가 아닌 것(공백, '-')은 처음부터 보여준다.
+    pub fn masked(&self) -> String {
+        self.word
+            .iter()
+            .map(|c| {
+                if self.is_revealed(*c) {
+                    c.to_string()
+                } else {
+                    "_".to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn is_revealed(&self, c: char) -> bool {
+        self.guessed_word || !c.is_alphabetic() || self.used.contains(&c)
+    }
+
+    pub fn used_letters(&self) -> &HashSet<char> {
+        &self.used
+    }
+
+    // 화면에 보여주기 좋게 정렬한 사용한 글자들
+    pub fn sorted_used_letters(&self) -> Vec<char> {
+        let mut letters: Vec<char> = self.used.iter().copied().collect();
+        letters.sort_unstable();
+        letters
+    }
+
+    // 단어의 글자 수 (바이트 수가 아니다)
+    pub fn length(&self) -> usize {
+        self.word.len()
+    }
+
+    // 단어에 나오는 서로 다른 글자의 수. 실수 없이 맞히려면 이만큼 추측해야 한다.
+    pub fn unique_letters(&self) -> usize {
+        self.word
+            .iter()
+            .filter(|c| c.is_alphabetic())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    pub fn lives(&self) -> u32 {
+        self.lives
+    }
+
+    pub fn max_lives(&self) -> u32 {
+        self.max_lives
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.history.len() as u32
+    }
+
+    pub fn history(&self) -> &[(String, bool)] {
+        &self.history
+    }
+
+    pub fn hints_left(&self) -> u32 {
+        self.hints_left
+    }
+
+    pub fn hints_used(&self) -> u32 {
+        self.hints_used
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.word.iter().all(|c| self.is_revealed(*c))
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.is_won() || self.lives == 0
+    }
+
+    pub fn reveal(&self) -> Option<String> {
+        if self.is_over() {
+            Some(self.word.iter().collect())
+        } else {
+            None
+        }
+    }
+}
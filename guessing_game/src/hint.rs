@@ -0,0 +1,92 @@
+use std::fmt;
+
+use crate::difficulty::GuessRange;
+use crate::messages::Messages;
+
+// 비밀 숫자와 얼마나 가까운지를 다섯 단계로 나눈다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Proximity {
+    Freezing,
+    Cold,
+    Warm,
+    Hot,
+    Burning,
+}
+
+impl Proximity {
+    // 거리를 범위 크기에 대한 백분율로 바꿔서 구간을 나눈다.
+    // 범위가 작으면 백분율이 크게 나오므로, 바로 옆 숫자는 항상 Burning으로 본다.
+    pub fn between(guess: u32, secret_number: u32, range: GuessRange) -> Proximity {
+        let distance = u64::from(guess.max(secret_number) - guess.min(secret_number));
+        let percent = distance * 100 / range.size();
+        match percent {
+            _ if distance <= 1 => Proximity::Burning,
+            0..=2 => Proximity::Burning,
+            3..=5 => Proximity::Hot,
+            6..=15 => Proximity::Warm,
+            16..=35 => Proximity::Cold,
+            _ => Proximity::Freezing,
+        }
+    }
+
+    // --json 모드에서 쓰는 이름. 화면에 보여주는 문장(Display)을 바꾸거나 번역해도 달라지지 않는다.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Proximity::Freezing => "freezing",
+            Proximity::Cold => "cold",
+            Proximity::Warm => "warm",
+            Proximity::Hot => "hot",
+            Proximity::Burning => "burning",
+        }
+    }
+
+    // 메시지 카탈로그의 키
+    pub fn key(&self) -> &'static str {
+        match self {
+            Proximity::Freezing => "proximity.freezing",
+            Proximity::Cold => "proximity.cold",
+            Proximity::Warm => "proximity.warm",
+            Proximity::Hot => "proximity.hot",
+            Proximity::Burning => "proximity.burning",
+        }
+    }
+}
+
+impl fmt::Display for Proximity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Messages::default().get(self.key()))
+    }
+}
+
+// 직전 추측과 비교해서 가까워졌는지 멀어졌는지
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trend {
+    Warmer,
+    Colder,
+    Same,
+}
+
+impl Trend {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Trend::Warmer => "trend.warmer",
+            Trend::Colder => "trend.colder",
+            Trend::Same => "trend.same",
+        }
+    }
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Messages::default().get(self.key()))
+    }
+}
+
+// hint 명령을 쓸 수 없는 이유
+#[derive(Debug, PartialEq)]
+pub enum HintError {
+    NoHintsLeft,
+    GameOver,
+    // 행맨에서 숨은 글자가 한 종류만 남아서, 알려주면 단어가 완성되어 버린다.
+    WouldSolve,
+}
@@ -0,0 +1,185 @@
+use std::io::{self, BufRead, Write};
+
+use crate::game::{step, Game, Outcome};
+use crate::messages::Messages;
+
+// --players만 주고 --rounds를 주지 않았을 때의 라운드 수
+pub const DEFAULT_ROUNDS: u32 = 3;
+
+// hot-seat: 한 터미널에서 여러 사람이 돌아가며 같은 비밀 숫자를 맞춘다.
+// 추측이 받아들여질 때마다 다음 사람에게 차례가 넘어가고, 맞춘 사람이 그 라운드를 이긴다.
+// 먼저 추측하는 쪽이 불리하므로 라운드마다 첫 차례를 한 사람씩 돌린다.
+
+// 한 라운드의 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundResult {
+    // 이긴 사람의 번호. 시도 횟수를 다 써버렸으면 None이다.
+    pub winner: Option<usize>,
+    // 사람마다 이번 라운드에 쓴 시도 횟수
+    pub attempts: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchReport {
+    pub players: Vec<String>,
+    // 끝까지 진행된 라운드들. 중간에 그만둔 라운드는 들어가지 않는다.
+    pub rounds: Vec<RoundResult>,
+    // quit이나 EOF로 중간에 그만뒀으면 false
+    pub finished: bool,
+}
+
+impl MatchReport {
+    pub fn wins(&self, player: usize) -> u32 {
+        self.rounds
+            .iter()
+            .filter(|round| round.winner == Some(player))
+            .count() as u32
+    }
+
+    pub fn attempts(&self, player: usize) -> u32 {
+        self.rounds.iter().map(|round| round.attempts[player]).sum()
+    }
+
+    // 가장 많이 이긴 사람들. 동점이면 여러 명이고, 아무도 이기지 못했으면 비어 있다.
+    pub fn leaders(&self) -> Vec<&str> {
+        let best = (0..self.players.len())
+            .map(|player| self.wins(player))
+            .max()
+            .unwrap_or(0);
+        if best == 0 {
+            return Vec::new();
+        }
+        (0..self.players.len())
+            .filter(|&player| self.wins(player) == best)
+            .map(|player| self.players[player].as_str())
+            .collect()
+    }
+
+    pub fn print(&self, messages: &Messages, output: &mut dyn Write) -> io::Result<()> {
+        let rounds = self.rounds.len() as u32;
+        writeln!(output, "{}", messages.get("hotseat.results"))?;
+        for (player, name) in self.players.iter().enumerate() {
+            let wins = self.wins(player);
+            let rate = (wins * 100).checked_div(rounds).unwrap_or(0);
+            writeln!(
+                output,
+                "{}",
+                messages.format(
+                    "hotseat.player",
+                    &[
+                        ("player", name),
+                        ("wins", &wins),
+                        ("rounds", &rounds),
+                        ("rate", &rate),
+                        ("attempts", &self.attempts(player)),
+                    ]
+                )
+            )?;
+        }
+
+        let leaders = self.leaders();
+        let names = leaders.join(", ");
+        match leaders.len() {
+            0 => Ok(()),
+            1 => writeln!(
+                output,
+                "{}",
+                messages.format("hotseat.winner", &[("players", &names)])
+            ),
+            _ => writeln!(
+                output,
+                "{}",
+                messages.format("hotseat.draw", &[("players", &names)])
+            ),
+        }
+    }
+}
+
+// players가 돌아가며 rounds 라운드를 한다.
+// new_game은 라운드 번호(1부터)를 받아서 그 라운드의 게임을 만든다.
+pub fn play_match<R, W, F>(
+    players: &[String],
+    rounds: u32,
+    mut new_game: F,
+    mut input: R,
+    mut output: W,
+) -> io::Result<MatchReport>
+where
+    R: BufRead,
+    W: Write,
+    F: FnMut(u32) -> Box<dyn Game>,
+{
+    let mut report = MatchReport {
+        players: players.to_vec(),
+        rounds: Vec::new(),
+        finished: false,
+    };
+    let mut messages = Messages::default();
+
+    for round in 1..=rounds {
+        let mut game = new_game(round);
+        messages = game.messages();
+        writeln!(
+            output,
+            "{}",
+            messages.format("hotseat.round", &[("round", &round), ("rounds", &rounds)])
+        )?;
+        game.write_intro(&mut output)?;
+
+        let mut attempts = vec![0; players.len()];
+        let mut turn = (round as usize - 1) % players.len();
+        let winner = loop {
+            writeln!(
+                output,
+                "{}",
+                messages.format("hotseat.prompt", &[("player", &players[turn])])
+            )?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                report.print(&messages, &mut output)?;
+                return Ok(report);
+            }
+
+            let before = game.attempts();
+            let mut feedback = Vec::new();
+            let outcome = step(&mut *game, &line, &mut feedback)?;
+            output.write_all(&feedback)?;
+            let used = game.attempts() - before;
+            attempts[turn] += used;
+
+            match outcome {
+                Some(Outcome::Won { .. }) => break Some(turn),
+                Some(Outcome::Lost { .. }) | Some(Outcome::Cheated { .. }) => break None,
+                Some(Outcome::Abandoned { .. }) => {
+                    report.print(&messages, &mut output)?;
+                    return Ok(report);
+                }
+                // 추측이 받아들여졌을 때만 차례가 넘어간다. 잘못된 입력이나 명령어는 다시 입력받는다.
+                None if used > 0 => turn = (turn + 1) % players.len(),
+                None => {}
+            }
+        };
+
+        match winner {
+            Some(player) => writeln!(
+                output,
+                "{}",
+                messages.format(
+                    "hotseat.round_won",
+                    &[("player", &players[player]), ("round", &round)]
+                )
+            )?,
+            None => writeln!(
+                output,
+                "{}",
+                messages.format("hotseat.round_lost", &[("round", &round)])
+            )?,
+        }
+        report.rounds.push(RoundResult { winner, attempts });
+    }
+
+    report.finished = true;
+    report.print(&messages, &mut output)?;
+    Ok(report)
+}
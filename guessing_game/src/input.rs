@@ -0,0 +1,216 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::num::IntErrorKind;
+
+use crate::difficulty::GuessRange;
+use crate::hangman::{normalize, HangmanGuess};
+use crate::messages::Messages;
+
+// 프롬프트에 입력할 수 있는 명령어들
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Quit,
+    Hint,
+    History,
+}
+
+// 한 줄을 검증한 결과. 추측이거나 명령어이다.
+// 숫자 맞추기에서는 u32, bulls and cows에서는 자릿수 목록(Vec<u8>)이 추측이 된다.
+#[derive(Debug, PartialEq)]
+pub enum Input<T = u32> {
+    Guess(T),
+    Command(Command),
+}
+
+// 입력을 받아들일 수 없는 이유를 종류별로 나눠둔다.
+// 예전처럼 parse 에러를 continue로 삼켜버리지 않고, 각각 다른 메시지를 보여줄 수 있다.
+#[derive(Debug, PartialEq)]
+pub enum InputError {
+    Empty,
+    NotANumber(String),
+    Negative(String),
+    TooLarge(String),
+    OutOfRange { value: u32, range: GuessRange },
+    // bulls and cows에서 자릿수가 맞지 않는 경우
+    WrongLength { expected: usize, got: usize },
+    // bulls and cows의 숫자는 모든 자리가 달라야 한다.
+    RepeatedDigit(char),
+    // reverse 모드에서 higher/lower/correct가 아닌 대답
+    NotAnAnswer(String),
+    // 행맨에서 글자가 아닌 것(숫자, 기호)이 들어간 추측
+    NotALetter(String),
+}
+
+impl InputError {
+    // 기계가 읽기 위한 에러 이름 (--json 모드에서 사용)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            InputError::Empty => "empty",
+            InputError::NotANumber(_) => "not_a_number",
+            InputError::Negative(_) => "negative",
+            InputError::TooLarge(_) => "too_large",
+            InputError::OutOfRange { .. } => "out_of_range",
+            InputError::WrongLength { .. } => "wrong_length",
+            InputError::RepeatedDigit(_) => "repeated_digit",
+            InputError::NotAnAnswer(_) => "not_an_answer",
+            InputError::NotALetter(_) => "not_a_letter",
+        }
+    }
+
+    // 고른 언어로 된 에러 메시지. 메시지 카탈로그의 키는 "error." + kind()이다.
+    pub fn message(&self, messages: &Messages) -> String {
+        match self {
+            InputError::Empty => messages.get("error.empty").to_string(),
+            InputError::NotANumber(text) => {
+                messages.format("error.not_a_number", &[("text", text)])
+            }
+            InputError::Negative(text) => messages.format("error.negative", &[("text", text)]),
+            InputError::TooLarge(text) => messages.format("error.too_large", &[("text", text)]),
+            InputError::OutOfRange { range, .. } => messages.format(
+                "error.out_of_range",
+                &[("min", &range.min()), ("max", &range.max())],
+            ),
+            InputError::WrongLength { expected, got } => messages.format(
+                "error.wrong_length",
+                &[("expected", expected), ("got", got)],
+            ),
+            InputError::RepeatedDigit(digit) => {
+                messages.format("error.repeated_digit", &[("digit", digit)])
+            }
+            InputError::NotAnAnswer(text) => {
+                messages.format("error.not_an_answer", &[("text", text)])
+            }
+            InputError::NotALetter(text) => {
+                messages.format("error.not_a_letter", &[("text", text)])
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message(&Messages::default()))
+    }
+}
+
+// 모든 게임 모드에서 같이 쓰는 명령어
+pub fn parse_command(text: &str) -> Option<Command> {
+    match text.trim().to_lowercase().as_str() {
+        "quit" => Some(Command::Quit),
+        "hint" => Some(Command::Hint),
+        "history" => Some(Command::History),
+        _ => None,
+    }
+}
+
+// 한 줄을 읽어서 명령어인지, 범위 안의 숫자인지 확인한다.
+pub fn parse_input(line: &str, range: GuessRange) -> Result<Input, InputError> {
+    let text = line.trim();
+    if text.is_empty() {
+        return Err(InputError::Empty);
+    }
+    if let Some(command) = parse_command(text) {
+        return Ok(Input::Command(command));
+    }
+
+    // parse 에러의 kind()를 보면 왜 실패했는지 알 수 있다.
+    let value: u32 = match text.parse() {
+        Ok(value) => value,
+        Err(e) => {
+            return Err(match e.kind() {
+                IntErrorKind::PosOverflow => InputError::TooLarge(text.to_string()),
+                // u32는 음수를 파싱할 수 없으므로 '-'로 시작하는 숫자인지 따로 확인한다.
+                _ if is_negative_number(text) => InputError::Negative(text.to_string()),
+                _ => InputError::NotANumber(text.to_string()),
+            });
+        }
+    };
+
+    if !range.contains(value) {
+        return Err(InputError::OutOfRange { value, range });
+    }
+    Ok(Input::Guess(value))
+}
+
+fn is_negative_number(text: &str) -> bool {
+    match text.strip_prefix('-') {
+        Some(digits) => !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()),
+        None => false,
+    }
+}
+
+// bulls and cows용. 서로 다른 숫자 digits개로 이루어져 있는지 확인한다.
+pub fn parse_code(line: &str, digits: usize) -> Result<Input<Vec<u8>>, InputError> {
+    let text = line.trim();
+    if text.is_empty() {
+        return Err(InputError::Empty);
+    }
+    if let Some(command) = parse_command(text) {
+        return Ok(Input::Command(command));
+    }
+    if is_negative_number(text) {
+        return Err(InputError::Negative(text.to_string()));
+    }
+    if !text.chars().all(|c| c.is_ascii_digit()) {
+        return Err(InputError::NotANumber(text.to_string()));
+    }
+
+    // 숫자만 있다는 걸 확인했으므로 글자 수와 바이트 수가 같다.
+    if text.len() != digits {
+        return Err(InputError::WrongLength {
+            expected: digits,
+            got: text.len(),
+        });
+    }
+
+    let mut code = Vec::with_capacity(digits);
+    for c in text.chars() {
+        let digit = c as u8 - b'0';
+        if code.contains(&digit) {
+            return Err(InputError::RepeatedDigit(c));
+        }
+        code.push(digit);
+    }
+    Ok(Input::Guess(code))
+}
+
+// reverse 모드용. 컴퓨터의 추측에 대한 대답을 Ordering으로 바꾼다.
+// 생각한 숫자가 추측보다 크면(higher) Greater, 작으면(lower) Less이다.
+pub fn parse_reply(line: &str) -> Result<Input<Ordering>, InputError> {
+    let text = line.trim();
+    if text.is_empty() {
+        return Err(InputError::NotAnAnswer(String::new()));
+    }
+    if let Some(command) = parse_command(text) {
+        return Ok(Input::Command(command));
+    }
+    match text.to_lowercase().as_str() {
+        "higher" | "h" => Ok(Input::Guess(Ordering::Greater)),
+        "lower" | "l" => Ok(Input::Guess(Ordering::Less)),
+        "correct" | "c" => Ok(Input::Guess(Ordering::Equal)),
+        _ => Err(InputError::NotAnAnswer(text.to_string())),
+    }
+}
+
+// 행맨용. 글자 하나면 글자 추측, 여러 글자면 단어 전체 추측이다.
+// 글자 수는 chars()로 센다. "가"는 3바이트지만 글자 하나다.
+pub fn parse_hangman(line: &str) -> Result<Input<HangmanGuess>, InputError> {
+    let text = line.trim();
+    if text.is_empty() {
+        return Err(InputError::NotALetter(String::new()));
+    }
+    if let Some(command) = parse_command(text) {
+        return Ok(Input::Command(command));
+    }
+    // 단어 안의 공백이나 '-'는 괜찮지만, 글자가 하나도 없거나 숫자가 섞여 있으면 안 된다.
+    let allowed = |c: char| c.is_alphabetic() || c == ' ' || c == '-' || c == '\'';
+    if !text.chars().all(allowed) || !text.chars().any(char::is_alphabetic) {
+        return Err(InputError::NotALetter(text.to_string()));
+    }
+
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) => Ok(Input::Guess(HangmanGuess::Letter(normalize(letter)))),
+        _ => Ok(Input::Guess(HangmanGuess::Word(text.to_string()))),
+    }
+}
@@ -0,0 +1,32 @@
+// 게임 로직은 라이브러리 크레이트에 두고, main.rs는 stdin/stdout을 연결해주는 역할만 한다.
+pub mod bench;
+pub mod bulls;
+pub mod cli;
+pub mod client;
+pub mod clock;
+pub mod difficulty;
+pub mod engine;
+pub mod game;
+pub mod hangman;
+pub mod hint;
+pub mod hotseat;
+pub mod input;
+pub mod messages;
+pub mod protocol;
+pub mod replay;
+pub mod reverse;
+pub mod score;
+pub mod scores;
+pub mod secret;
+pub mod server;
+pub mod session;
+pub mod settings;
+pub mod solver;
+pub mod timed;
+pub mod tui;
+
+pub use crate::bulls::BullsCowsEngine;
+pub use crate::difficulty::{Difficulty, GuessRange};
+pub use crate::engine::{GameEngine, GuessError};
+pub use crate::game::{play, play_game, Game, Outcome};
+pub use crate::secret::SecretSource;
@@ -1,55 +1,370 @@
-use std::io;
+use std::env;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, ErrorKind};
+use std::path::Path;
+use std::process;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 // rust는 기본적으로 prelude에 있는 몇가지 타입들을 제공한다.
 // 다만 이 타입에 속해있지 않은 것들을 사용하기 위해서는 위와 같이 불러와야 한다.
-use rand::Rng;
-// Rng는 random number generator 가 구현된 메서드다.
-use std::cmp::Ordering;
-// Ordering은 Less, Greater, Equal을 가지는 enum이다.
+
+use errors::report::{self, Category, Context, Format, Report};
+use guessing_game::cli::{self, CliError, GameMode, Mode, Options};
+use guessing_game::clock::SystemClock;
+use guessing_game::game::play_with;
+use guessing_game::messages::{Lang, Messages};
+use guessing_game::replay::{replay, Recorder, SessionLog};
+use guessing_game::score::score;
+use guessing_game::scores::{self, ScoreEntry, ScoreTable};
+use guessing_game::server::Server;
+use guessing_game::session::SessionConfig;
+use guessing_game::settings;
+use guessing_game::timed::Timed;
+use guessing_game::tui::{self, Tui};
+use guessing_game::{
+    bench, client, hangman, hotseat, play_game, protocol, secret, Game, Outcome, SecretSource,
+};
+use serde_json::json;
+// 같은 패키지의 lib.rs는 패키지 이름(guessing_game)으로 불러올 수 있다.
+
+// bench에서 --games를 주지 않았을 때 돌릴 판 수
+const DEFAULT_BENCH_GAMES: u32 = 1000;
+const DEFAULT_HOST: &str = "127.0.0.1";
+const DEFAULT_PORT: u16 = 7878;
+const PROGRAM: &str = "guessing_game";
+
+// 실패하면 expect로 panic하지 않고 에러를 main까지 돌려준다.
+// main에서 report로 원인들과 함께 출력하고, 에러 종류에 맞는 exit code로 끝낸다.
+type AppResult = Result<(), Box<dyn Error>>;
 
 fn main() {
-    println!("Guess the number!");
-
-    let secret_number = rand::thread_rng().gen_range(1, 101);
-
-    loop {
-        println!("Please input your guess.");
-    
-        let mut guess = String::new(); // mutable
-        //let guess = String::new(); // immutable - 기본
-    
-        // 위에서 use std:io를 입력하지 않았으면, std::io::stdin().~~ 라고 써야한다.
-        // read_line은 유저가 standard input에 입력한 것을 받아서 문자열에 넣어준다.
-        // &는 reference를 뜻한다.
-        io::stdin().read_line(&mut guess)
-            .expect("Failed to read line");
-        // read_line은 io::Result 라는 return 타입을 가지고 있다.
-        // Result 타입은 enum 타입으로, Ok와 Err이라는 고정값을 가지고 있다.
-        // 모든 타입들과 마찬가지로 메서드를 가지고 있는데,
-        // io::Result의 경우 반환값이 Err일때 실행되는 expect라는 메서드를 가지고 있다.
-        // 위 코드에서 read_line이 Err을 반환할 경우 
-        // expect를 실행하며 위 메시지를 출력하고 프로그램을 종료한다.
-    
-        // match를 integer와 integer의 비교로 하기 위해서 parse한다.
-        // shadowing이 가능하다.
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => continue,
+    let args: Vec<String> = env::args().skip(1).collect();
+    // 설정 파일 < 환경 변수 < 커맨드라인 인자 순서로 덮어쓴다.
+    let config_path = cli::config_path(&args).unwrap_or_else(|err| exit_with_cli_error(&err));
+    let config = settings::load_config(config_path.as_deref(), env::vars()).unwrap_or_else(|err| {
+        report::exit(PROGRAM, Format::Text, &Report::without_backtrace(&err))
+    });
+    let mut options =
+        Options::parse_with(args, &config).unwrap_or_else(|err| exit_with_cli_error(&err));
+    // --lang이 없으면 LANG 환경변수(예: ko_KR.UTF-8)를 따른다.
+    options.lang = options.lang.or_else(Lang::from_env);
+    // --json 모드에서는 에러도 JSON으로 알린다.
+    let format = if options.json {
+        Format::Json
+    } else {
+        Format::Text
+    };
+
+    let result = match options.mode {
+        Mode::Play => play(options),
+        Mode::Bench => run_bench(options),
+        Mode::Serve => serve(options),
+        Mode::Connect => connect(options),
+        Mode::Replay => run_replay(options),
+    };
+    if let Err(error) = result {
+        report::exit(PROGRAM, format, &Report::new(&*error));
+    }
+}
+
+fn exit_with_cli_error(err: &CliError) -> ! {
+    let report = match err {
+        // 인자의 값이 잘못된 것은 설정 파일, 환경 변수와 같은 설정 에러다.
+        CliError::Config(e) => Report::without_backtrace(e),
+        // 잘못된 인자라는 것은 에러만 보고는 알 수 없으므로 종류를 직접 정해준다.
+        CliError::Usage(_) => Report::without_backtrace(err).with_category(Category::Usage),
+    };
+    report::exit(PROGRAM, Format::Text, &report)
+}
+
+// --seed가 주어지면 항상 같은 비밀 숫자가, 아니면 매번 다른 숫자가 나온다.
+// 두 타입이 다르기 때문에 trait object(Box<dyn SecretSource>)로 묶어준다.
+fn secret_source(seed: Option<u64>) -> Box<dyn SecretSource> {
+    match seed {
+        Some(seed) => Box::new(secret::seeded(seed)),
+        None => Box::new(rand::thread_rng()),
+    }
+}
+
+fn play(options: Options) -> AppResult {
+    let scores_path = options
+        .scores_file
+        .clone()
+        .unwrap_or_else(scores::default_path);
+
+    if options.show_scores {
+        let table = ScoreTable::load(&scores_path).context("Failed to read high scores")?;
+        table
+            .print(
+                &Messages::new(options.lang.unwrap_or_default()),
+                io::stdout(),
+            )
+            .context("Failed to print high scores")?;
+        return Ok(());
+    }
+
+    // 기록해두면 나중에 replay할 수 있도록, seed가 없으면 무작위로 하나 정해서 사용한다.
+    let seed = options.seed.unwrap_or_else(rand::random);
+    let words = match &options.words {
+        Some(path) => Some(
+            hangman::load_words(path)
+                .context(format!("Failed to read word list {}", path.display()))?,
+        ),
+        None => None,
+    };
+    let config = session_config(&options, seed, words.as_deref());
+    if !options.players.is_empty() {
+        return play_hotseat(&options, &config, words.as_deref(), &scores_path);
+    }
+    // 게임 종류만 다르고, 입력 루프와 점수 기록은 같이 사용한다.
+    let mut game = with_timing(config.build(), &options);
+
+    // 위에서 use std:io를 입력하지 않았으면, std::io::stdin().~~ 라고 써야한다.
+    // lock()을 하면 BufRead가 구현된 StdinLock을 얻을 수 있다.
+    let stdin = io::stdin();
+    let started = Instant::now();
+    let outcome = match &options.record {
+        Some(path) => {
+            let file = File::create(path).context("Failed to create session log")?;
+            let mut recorder = Recorder::new(BufWriter::new(file), &config)
+                .context("Failed to write session log")?;
+            let outcome = play_with(&mut *game, stdin.lock(), io::stdout(), |game, line, out| {
+                recorder.record_step(game, line, out)
+            })
+            .context("Failed to read line")?;
+            recorder
+                .record_outcome(&outcome)
+                .context("Failed to write session log")?;
+            outcome
+        }
+        // --json은 GameEngine을 직접 다루므로 숫자 맞추기 엔진을 따로 만든다.
+        None if options.json => {
+            let mut engine = config.build_number();
+            let outcome = protocol::play_json(&mut engine, stdin.lock(), io::stdout())
+                .context("Failed to read line")?;
+            game = Box::new(engine);
+            outcome
+        }
+        None if options.tui => {
+            let engine = config.build_number();
+            let mut tui = Tui::new(engine, Box::new(SystemClock::new()));
+            let outcome = tui::run(&mut tui).context("Failed to run the terminal UI")?;
+            game = Box::new(tui.into_engine());
+            outcome
+        }
+        None => play_game(&mut *game, stdin.lock(), io::stdout()).context("Failed to read line")?,
+    };
+    // play_game은 io::Result 라는 return 타입을 가지고 있다.
+    // Result 타입은 enum 타입으로, Ok와 Err이라는 고정값을 가지고 있다.
+    // 위 코드에서 play_game이 Err을 반환할 경우
+    // context로 무엇을 하다 실패했는지 덧붙이고, ?로 main까지 에러를 돌려준다.
+    let performance = game.performance(started.elapsed());
+
+    // 이긴 경우에만 점수를 매기고 점수표에 기록한다.
+    // reverse 모드에서 이긴 쪽은 컴퓨터이므로 기록하지 않는다.
+    if options.game == GameMode::Reverse {
+        return Ok(());
+    }
+    if let Outcome::Won { attempts } = outcome {
+        let points = score(&performance);
+        let messages = Messages::new(config.lang);
+        if !options.json {
+            println!("{}", messages.format("your_score", &[("score", &points)]));
+        }
+
+        let entry = ScoreEntry {
+            name: options.name.unwrap_or_else(player_name),
+            score: points,
+            attempts,
+            hints_used: performance.hints_used,
+            difficulty: config.label,
+            elapsed_secs: performance.elapsed.as_secs(),
+            recorded_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
         };
-    
-        println!("You guessed: {}", guess);
-        // {}는 placeholder로 println에 다음 인자로 들어오는 값들을 넣어준다.
-    
-        // match는 컨트롤 제어 연산자로, 어떤 값을 연속속적인 패턴과 비교한 후 맞는 패턴에 대해 코드를 실행할 수 있게 해주는 연산자다.
-        // guess와 secret_number를 비교하여 아래 패턴 중 맞는 패턴에 해당하는 코드를 실행한다.
-        // cmp 메서드를 실행하면, Ordering enum 중 하나의 값이 나온다.
-        match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal => {
-                println!("You win!");
-                break;
+        let mut table = ScoreTable::load(&scores_path).context("Failed to read high scores")?;
+        let rank = table.insert(entry);
+        if options.json {
+            let event = json!({ "event": "score", "score": points, "rank": rank });
+            protocol::send(&mut io::stdout(), event).context("Failed to write score")?;
+        }
+        if let Some(rank) = rank {
+            if !options.json {
+                println!("{}", messages.format("new_high_score", &[("rank", &rank)]));
             }
+            table
+                .save(&scores_path)
+                .context("Failed to save high scores")?;
+        }
+    }
+    Ok(())
+}
+
+// --time-limit이나 --speedrun이 있으면 시간을 재는 Timed로 감싼다.
+fn with_timing(game: Box<dyn Game>, options: &Options) -> Box<dyn Game> {
+    if options.time_limit.is_none() && !options.speedrun {
+        return game;
+    }
+    let mut timed = Timed::new(game, Box::new(SystemClock::new()));
+    if let Some(limit) = options.time_limit {
+        timed = timed.with_time_limit(limit);
+    }
+    if options.speedrun {
+        timed = timed.with_speedrun();
+    }
+    Box::new(timed)
+}
+
+// --words로 단어 목록을 줬으면 행맨의 단어를 그 목록에서 고른다.
+fn session_config(options: &Options, seed: u64, words: Option<&[String]>) -> SessionConfig {
+    let config = SessionConfig::from_options(options, seed);
+    match words {
+        Some(words) => config.with_words(words),
+        None => config,
+    }
+}
+
+fn play_hotseat(
+    options: &Options,
+    config: &SessionConfig,
+    words: Option<&[String]>,
+    scores_path: &Path,
+) -> AppResult {
+    let rounds = options.rounds.unwrap_or(hotseat::DEFAULT_ROUNDS);
+    let stdin = io::stdin();
+    // 라운드마다 seed를 하나씩 바꿔서 다른 비밀 숫자(단어)가 나오게 한다.
+    let report = hotseat::play_match(
+        &options.players,
+        rounds,
+        |round| {
+            let seed = config.seed.wrapping_add(u64::from(round));
+            with_timing(session_config(options, seed, words).build(), options)
+        },
+        stdin.lock(),
+        io::stdout(),
+    )
+    .context("Failed to read line")?;
+    if report.rounds.is_empty() {
+        return Ok(());
+    }
+
+    // 끝난 라운드들만 사람별 기록에 더한다.
+    let messages = Messages::new(config.lang);
+    let mut table = ScoreTable::load(scores_path).context("Failed to read high scores")?;
+    for (player, name) in report.players.iter().enumerate() {
+        for round in &report.rounds {
+            table.record_round(name, round.winner == Some(player), round.attempts[player]);
         }
+        let stats = table.player(name).expect("player was just recorded");
+        let line = messages.format(
+            "hotseat.overall",
+            &[
+                ("player", name),
+                ("wins", &stats.rounds_won),
+                ("played", &stats.rounds_played),
+                ("rate", &stats.win_rate()),
+            ],
+        );
+        println!("{}", line);
     }
-    
+    table
+        .save(scores_path)
+        .context("Failed to save high scores")?;
+    Ok(())
+}
+
+fn run_bench(options: Options) -> AppResult {
+    let games = options.games.unwrap_or(DEFAULT_BENCH_GAMES);
+    let mut source = secret_source(options.seed);
+    let mut strategy = options.strategy.build(options.seed);
+
+    let messages = Messages::new(options.lang.unwrap_or_default());
+    println!(
+        "{}",
+        messages.format(
+            "bench.header",
+            &[
+                ("strategy", &options.strategy),
+                ("difficulty", &options.difficulty),
+                ("attempts", &options.difficulty.max_attempts()),
+            ],
+        )
+    );
+    let report = bench::run(options.difficulty, games, &mut *strategy, &mut *source);
+    report
+        .print(&messages, io::stdout())
+        .context("Failed to print report")?;
+    Ok(())
+}
+
+fn serve(options: Options) -> AppResult {
+    let host = options.host.as_deref().unwrap_or(DEFAULT_HOST);
+    let port = options.port.unwrap_or(DEFAULT_PORT);
+    // 서버는 여러 스레드에서 비밀 숫자를 뽑으므로 Send가 아닌 thread_rng는 쓸 수 없다.
+    // 시드가 없으면 무작위 시드로 StdRng를 만든다.
+    let seed = options.seed.unwrap_or_else(rand::random);
+    let source = Box::new(secret::seeded(seed));
+
+    let server = Server::bind((host, port), options.difficulty.range(), source)
+        .context("Failed to bind server")?
+        .with_lang(options.lang.unwrap_or_default());
+    let addr = server
+        .local_addr()
+        .context("Failed to read local address")?;
+    let messages = Messages::new(options.lang.unwrap_or_default());
+    println!("{}", messages.format("server.serving", &[("addr", &addr)]));
+    server.run().context("Server stopped")?;
+    Ok(())
+}
+
+fn connect(options: Options) -> AppResult {
+    let host = options.host.as_deref().unwrap_or(DEFAULT_HOST);
+    let port = options.port.unwrap_or(DEFAULT_PORT);
+    let stdin = io::stdin();
+    client::connect((host, port), stdin.lock(), io::stdout()).context("Connection failed")?;
+    Ok(())
+}
+
+fn run_replay(options: Options) -> AppResult {
+    let path = options
+        .replay_file
+        .expect("replay file is checked by Options::parse");
+    let text = fs::read_to_string(&path)
+        .context(format!("Failed to read session log {}", path.display()))?;
+    // 형식이 잘못된 기록 파일은 InvalidData로 알린다.
+    let log = SessionLog::parse(&text).map_err(|err| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), err),
+        )
+    })?;
+
+    let report = replay(&log).context("Failed to replay session")?;
+    let messages = Messages::new(options.lang.unwrap_or_default());
+    if report.is_ok() {
+        println!(
+            "{}",
+            messages.format("replay.ok", &[("steps", &report.steps)])
+        );
+    } else {
+        for mismatch in &report.mismatches {
+            println!("{}", mismatch.message(&messages));
+        }
+        let summary = messages.format(
+            "replay.mismatches",
+            &[
+                ("steps", &report.steps),
+                ("count", &report.mismatches.len()),
+            ],
+        );
+        println!("{}", summary);
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn player_name() -> String {
+    env::var("USER").unwrap_or_else(|_| String::from("player"))
 }
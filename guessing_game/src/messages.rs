@@ -0,0 +1,587 @@
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+// 화면에 보여주는 문장들을 언어별로 모아둔 메시지 카탈로그.
+// 코드에서는 키("number.too_small")로 문장을 찾고, {이름} 자리에 값을 채워 넣는다.
+// 언어를 추가하려면 Lang에 항목을 더하고, EN과 같은 키를 모두 가진 목록을 하나 만들면 된다.
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Lang {
+    #[default]
+    En,
+    Ko,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::En, Lang::Ko];
+
+    // "ko", "ko_KR.UTF-8", "en-US" 같은 로케일 문자열에서 언어 부분만 본다.
+    pub fn from_locale(locale: &str) -> Option<Lang> {
+        let language = locale.split(['_', '-', '.', '@']).next().unwrap_or("");
+        language.to_lowercase().parse().ok()
+    }
+
+    // LANG 환경 변수로 언어를 고른다. 지원하지 않는 언어(C, POSIX 등)면 None이다.
+    pub fn from_env() -> Option<Lang> {
+        env::var("LANG")
+            .ok()
+            .and_then(|locale| Lang::from_locale(&locale))
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lang::En => write!(f, "en"),
+            Lang::Ko => write!(f, "ko"),
+        }
+    }
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Lang, String> {
+        match s {
+            "en" => Ok(Lang::En),
+            "ko" => Ok(Lang::Ko),
+            other => Err(format!("unknown language: {} (expected en or ko)", other)),
+        }
+    }
+}
+
+// (키, 문장) 목록. 키의 순서는 상관없지만 보기 좋게 EN과 같은 순서로 맞춰둔다.
+type Bundle = &'static [(&'static str, &'static str)];
+
+const EN: Bundle = &[
+    // 모든 게임에서 같이 쓰는 문장
+    ("prompt", "Please input your guess."),
+    ("bye", "Bye!"),
+    ("you_guessed", "You guessed: {guess}"),
+    ("win", "You win!"),
+    ("attempts_total", "You have {attempts} attempts."),
+    ("attempts_left", "Attempts left: {left}"),
+    ("no_hints_left", "No hints left."),
+    ("no_guesses", "No guesses yet."),
+    ("your_score", "Your score: {score}"),
+    ("new_high_score", "New high score! Rank #{rank}"),
+    ("game_over", "The game is already over."),
+    // 숫자 맞추기
+    ("number.intro", "Guess the number!"),
+    (
+        "number.range",
+        "The secret number is between {min} and {max}.",
+    ),
+    ("number.too_small", "Too small!"),
+    ("number.too_big", "Too big!"),
+    ("number.lost", "You lose! The secret number was {secret}."),
+    (
+        "number.hint",
+        "Hint: the secret number is between {min} and {max}. ({left} hints left)",
+    ),
+    ("number.result.too_small", "too small"),
+    ("number.result.too_big", "too big"),
+    ("number.result.correct", "correct"),
+    // hot/cold
+    ("proximity.freezing", "Freezing"),
+    ("proximity.cold", "Cold"),
+    ("proximity.warm", "Warm"),
+    ("proximity.hot", "Hot"),
+    ("proximity.burning", "Burning"),
+    ("trend.warmer", "warmer than your last guess"),
+    ("trend.colder", "colder than your last guess"),
+    ("trend.same", "as far as your last guess"),
+    // bulls and cows
+    ("bulls.intro", "Bulls and Cows!"),
+    (
+        "bulls.rules",
+        "Guess the {digits}-digit code. Every digit is different.",
+    ),
+    (
+        "bulls.explain",
+        "Bulls: right digit in the right place. Cows: right digit in the wrong place.",
+    ),
+    ("bulls.score", "{bulls} bulls, {cows} cows"),
+    (
+        "bulls.hint",
+        "Hint: digit {position} is {digit}. ({left} hints left)",
+    ),
+    ("bulls.lost", "You lose! The code was {secret}."),
+    // 제한 시간, speedrun
+    ("timed.intro", "You have {seconds} seconds for each guess."),
+    (
+        "timed.too_slow",
+        "Too slow! You took {taken}s, the limit is {limit}s.",
+    ),
+    ("speedrun.intro", "Speedrun! The clock is running."),
+    ("speedrun.split", "{number}. {guess}  +{split}s  ({total}s)"),
+    ("speedrun.total", "Total time: {total}s"),
+    // hot-seat 대전
+    ("hotseat.round", "Round {round} of {rounds}"),
+    ("hotseat.prompt", "{player}, please input your guess."),
+    ("hotseat.round_won", "{player} wins round {round}!"),
+    ("hotseat.round_lost", "Nobody wins round {round}."),
+    ("hotseat.results", "Match results:"),
+    (
+        "hotseat.player",
+        "{player}: {wins} of {rounds} rounds won ({rate}%), {attempts} attempts",
+    ),
+    ("hotseat.winner", "{players} wins the match!"),
+    ("hotseat.draw", "The match is a draw between {players}."),
+    (
+        "hotseat.overall",
+        "{player}: {wins} of {played} rounds won overall ({rate}%)",
+    ),
+    // reverse: 컴퓨터가 맞추는 모드
+    (
+        "reverse.intro",
+        "Think of a number between {min} and {max}, and I'll guess it.",
+    ),
+    ("reverse.rules", "Answer higher, lower, or correct."),
+    (
+        "reverse.prompt",
+        "Is your number higher, lower, or correct?",
+    ),
+    ("reverse.guess", "My guess: {guess}"),
+    (
+        "reverse.found",
+        "I found your number {guess} in {attempts} guesses!",
+    ),
+    (
+        "reverse.cheating",
+        "You're cheating! No number fits these answers:",
+    ),
+    ("reverse.answer_higher", "  #{step}: higher than {guess}"),
+    ("reverse.answer_lower", "  #{step}: lower than {guess}"),
+    ("reverse.range", "  the number is between {min} and {max}"),
+    ("reverse.result.higher", "higher"),
+    ("reverse.result.lower", "lower"),
+    ("reverse.result.correct", "correct"),
+    ("reverse.no_hints", "There are no hints in this mode."),
+    // 행맨
+    ("hangman.intro", "Hangman!"),
+    (
+        "hangman.rules",
+        "Guess the word one letter at a time, or type the whole word. You have {lives} lives.",
+    ),
+    ("hangman.prompt", "Please input a letter or the whole word."),
+    ("hangman.word", "Word: {word}"),
+    ("hangman.used", "Used letters: {letters}"),
+    ("hangman.lives", "Lives left: {lives}"),
+    ("hangman.hit", "Yes! '{letter}' is in the word."),
+    ("hangman.miss", "No '{letter}' in the word."),
+    ("hangman.already_used", "You already tried '{letter}'."),
+    ("hangman.wrong_word", "'{word}' is not the word."),
+    ("hangman.lost", "You lose! The word was {secret}."),
+    (
+        "hangman.hint",
+        "Hint: the word has '{letter}'. ({left} hints left)",
+    ),
+    (
+        "hangman.hint_would_solve",
+        "A hint would give away the whole word. Guess the last letter yourself.",
+    ),
+    ("hangman.result.hit", "hit"),
+    ("hangman.result.miss", "miss"),
+    // 여러 명이 접속하는 서버 (serve)
+    (
+        "server.welcome",
+        "Welcome, player {id}! Round {round}: guess the number between {min} and {max}.",
+    ),
+    ("server.joined", "Player {id} joined."),
+    ("server.left", "Player {id} left."),
+    (
+        "server.round",
+        "Round {round}: guess the number between {min} and {max}.",
+    ),
+    (
+        "server.won",
+        "Player {id} guessed {guess} and won round {round}!",
+    ),
+    (
+        "server.hint",
+        "Hint: the secret number is between {min} and {max}.",
+    ),
+    ("server.history", "Guesses this round: {guesses}"),
+    (
+        "server.round_over",
+        "This round is already over. Starting a new round.",
+    ),
+    ("server.serving", "Serving guessing game on {addr}"),
+    // 점수표 (--scores)
+    ("scores.empty", "No high scores yet."),
+    (
+        "scores.header",
+        "rank  name              score attempts    time  difficulty",
+    ),
+    (
+        "scores.players_header",
+        "player            played    won  win rate  attempts",
+    ),
+    // 세션 기록 다시 돌려보기 (replay)
+    ("replay.ok", "Replayed {steps} steps: all feedback matches."),
+    (
+        "replay.mismatches",
+        "Replayed {steps} steps: {count} mismatches.",
+    ),
+    ("replay.step", "step {step} (input {input}):"),
+    ("replay.expected", "  expected: {lines}"),
+    ("replay.actual", "  actual:   {lines}"),
+    // 전략 벤치마크 (bench)
+    (
+        "bench.header",
+        "strategy: {strategy}, difficulty: {difficulty} ({attempts} attempts)",
+    ),
+    (
+        "bench.summary",
+        "games: {games}, wins: {wins}, losses: {losses}",
+    ),
+    (
+        "bench.attempts",
+        "mean attempts: {mean}, max attempts: {max}",
+    ),
+    // --tui 화면
+    ("tui.plausible", "Possible: {min} - {max}"),
+    ("tui.attempts", "Attempts: {attempts}"),
+    ("tui.hints", "Hints left: {hints}"),
+    ("tui.score", "Score if you win now: {score}"),
+    ("tui.time", "Time: {secs}s"),
+    ("tui.history", "History"),
+    ("tui.ruled_out", "{guess} is already ruled out."),
+    (
+        "tui.help",
+        "Enter: guess, hint/history: commands, Esc: quit",
+    ),
+    ("tui.press_key", "Press any key to exit."),
+    // 잘못된 입력
+    ("error.empty", "Please type a number."),
+    (
+        "error.not_a_number",
+        "'{text}' is not a number. Type a number, or quit, hint, history.",
+    ),
+    (
+        "error.negative",
+        "{text} is negative. The secret number is positive.",
+    ),
+    ("error.too_large", "{text} is way too large."),
+    (
+        "error.out_of_range",
+        "Please guess a number between {min} and {max}.",
+    ),
+    (
+        "error.wrong_length",
+        "Please type {expected} digits, got {got}.",
+    ),
+    (
+        "error.repeated_digit",
+        "The digit {digit} is repeated. Every digit must be different.",
+    ),
+    (
+        "error.not_an_answer",
+        "'{text}' is not an answer. Type higher, lower, or correct.",
+    ),
+    ("error.not_a_letter", "'{text}' is not a letter or a word."),
+];
+
+const KO: Bundle = &[
+    ("prompt", "추측한 숫자를 입력하세요."),
+    ("bye", "안녕히 가세요!"),
+    ("you_guessed", "입력한 추측: {guess}"),
+    ("win", "정답입니다!"),
+    ("attempts_total", "기회는 {attempts}번입니다."),
+    ("attempts_left", "남은 기회: {left}"),
+    ("no_hints_left", "남은 힌트가 없습니다."),
+    ("no_guesses", "아직 추측한 기록이 없습니다."),
+    ("your_score", "점수: {score}"),
+    ("new_high_score", "최고 기록 갱신! {rank}위"),
+    ("game_over", "게임이 이미 끝났습니다."),
+    ("number.intro", "숫자를 맞혀보세요!"),
+    (
+        "number.range",
+        "비밀 숫자는 {min}부터 {max} 사이에 있습니다.",
+    ),
+    ("number.too_small", "너무 작습니다!"),
+    ("number.too_big", "너무 큽니다!"),
+    ("number.lost", "졌습니다! 비밀 숫자는 {secret}입니다."),
+    (
+        "number.hint",
+        "힌트: 비밀 숫자는 {min}부터 {max} 사이에 있습니다. (남은 힌트 {left}개)",
+    ),
+    ("number.result.too_small", "작음"),
+    ("number.result.too_big", "큼"),
+    ("number.result.correct", "정답"),
+    ("proximity.freezing", "꽁꽁"),
+    ("proximity.cold", "차가움"),
+    ("proximity.warm", "따뜻함"),
+    ("proximity.hot", "뜨거움"),
+    ("proximity.burning", "불타는 중"),
+    ("trend.warmer", "지난번보다 가까워졌습니다"),
+    ("trend.colder", "지난번보다 멀어졌습니다"),
+    ("trend.same", "지난번과 거리가 같습니다"),
+    ("bulls.intro", "숫자 야구!"),
+    (
+        "bulls.rules",
+        "{digits}자리 숫자를 맞혀보세요. 모든 자리의 숫자는 서로 다릅니다.",
+    ),
+    (
+        "bulls.explain",
+        "스트라이크: 숫자와 자리가 모두 맞음. 볼: 숫자는 있지만 자리가 다름.",
+    ),
+    ("bulls.score", "{bulls} 스트라이크, {cows} 볼"),
+    (
+        "bulls.hint",
+        "힌트: {position}번째 자리는 {digit}입니다. (남은 힌트 {left}개)",
+    ),
+    ("bulls.lost", "졌습니다! 정답은 {secret}입니다."),
+    ("timed.intro", "추측마다 {seconds}초 안에 입력해야 합니다."),
+    (
+        "timed.too_slow",
+        "너무 늦었습니다! {taken}초 걸렸습니다. (제한 {limit}초)",
+    ),
+    ("speedrun.intro", "스피드런! 시간을 재기 시작합니다."),
+    (
+        "speedrun.split",
+        "{number}. {guess}  +{split}초  ({total}초)",
+    ),
+    ("speedrun.total", "총 걸린 시간: {total}초"),
+    ("hotseat.round", "{rounds}라운드 중 {round}라운드"),
+    ("hotseat.prompt", "{player}님, 추측한 숫자를 입력하세요."),
+    (
+        "hotseat.round_won",
+        "{player}님이 {round}라운드에서 이겼습니다!",
+    ),
+    (
+        "hotseat.round_lost",
+        "{round}라운드는 아무도 이기지 못했습니다.",
+    ),
+    ("hotseat.results", "경기 결과:"),
+    (
+        "hotseat.player",
+        "{player}: {rounds}라운드 중 {wins}번 승리 ({rate}%), 시도 {attempts}번",
+    ),
+    ("hotseat.winner", "{players}님이 경기에서 이겼습니다!"),
+    ("hotseat.draw", "{players}님이 비겼습니다."),
+    (
+        "hotseat.overall",
+        "{player}: 지금까지 {played}라운드 중 {wins}번 승리 ({rate}%)",
+    ),
+    (
+        "reverse.intro",
+        "{min}부터 {max} 사이의 숫자를 하나 생각하세요. 제가 맞혀볼게요.",
+    ),
+    (
+        "reverse.rules",
+        "higher(더 큼), lower(더 작음), correct(정답)로 대답하세요.",
+    ),
+    (
+        "reverse.prompt",
+        "생각한 숫자가 더 큰가요, 작은가요, 아니면 정답인가요?",
+    ),
+    ("reverse.guess", "제 추측: {guess}"),
+    (
+        "reverse.found",
+        "{attempts}번 만에 {guess}을(를) 맞혔습니다!",
+    ),
+    (
+        "reverse.cheating",
+        "거짓말이죠! 이 대답들을 모두 만족하는 숫자가 없습니다:",
+    ),
+    ("reverse.answer_higher", "  #{step}: {guess}보다 큼"),
+    ("reverse.answer_lower", "  #{step}: {guess}보다 작음"),
+    ("reverse.range", "  숫자는 {min}부터 {max} 사이"),
+    ("reverse.result.higher", "더 큼"),
+    ("reverse.result.lower", "더 작음"),
+    ("reverse.result.correct", "정답"),
+    ("reverse.no_hints", "이 모드에는 힌트가 없습니다."),
+    ("hangman.intro", "행맨!"),
+    (
+        "hangman.rules",
+        "글자를 하나씩 맞히거나 단어 전체를 입력하세요. 목숨은 {lives}개입니다.",
+    ),
+    ("hangman.prompt", "글자 하나나 단어 전체를 입력하세요."),
+    ("hangman.word", "단어: {word}"),
+    ("hangman.used", "사용한 글자: {letters}"),
+    ("hangman.lives", "남은 목숨: {lives}"),
+    ("hangman.hit", "맞았습니다! '{letter}'이(가) 들어 있습니다."),
+    ("hangman.miss", "'{letter}'은(는) 없습니다."),
+    (
+        "hangman.already_used",
+        "'{letter}'은(는) 이미 입력했습니다.",
+    ),
+    ("hangman.wrong_word", "'{word}'은(는) 정답이 아닙니다."),
+    ("hangman.lost", "졌습니다! 정답은 {secret}입니다."),
+    (
+        "hangman.hint",
+        "힌트: '{letter}'이(가) 들어 있습니다. (남은 힌트 {left}개)",
+    ),
+    (
+        "hangman.hint_would_solve",
+        "힌트를 주면 단어가 완성되어 버립니다. 마지막 글자는 직접 맞혀보세요.",
+    ),
+    ("hangman.result.hit", "있음"),
+    ("hangman.result.miss", "없음"),
+    (
+        "server.welcome",
+        "{id}번 플레이어님, 환영합니다! {round}라운드: {min}부터 {max} 사이의 숫자를 맞혀보세요.",
+    ),
+    ("server.joined", "{id}번 플레이어가 들어왔습니다."),
+    ("server.left", "{id}번 플레이어가 나갔습니다."),
+    (
+        "server.round",
+        "{round}라운드: {min}부터 {max} 사이의 숫자를 맞혀보세요.",
+    ),
+    (
+        "server.won",
+        "{id}번 플레이어가 {guess}을(를) 맞혀서 {round}라운드에서 이겼습니다!",
+    ),
+    (
+        "server.hint",
+        "힌트: 비밀 숫자는 {min}부터 {max} 사이에 있습니다.",
+    ),
+    ("server.history", "이번 라운드의 추측: {guesses}"),
+    (
+        "server.round_over",
+        "이번 라운드는 이미 끝났습니다. 새 라운드를 시작합니다.",
+    ),
+    (
+        "server.serving",
+        "{addr}에서 숫자 맞추기 서버를 시작했습니다.",
+    ),
+    ("scores.empty", "아직 최고 기록이 없습니다."),
+    (
+        "scores.header",
+        "순위  이름              점수     시도    시간  난이도",
+    ),
+    (
+        "scores.players_header",
+        "플레이어          라운드   승리    승률      시도",
+    ),
+    (
+        "replay.ok",
+        "{steps}단계를 다시 돌려봤습니다: 출력이 모두 같습니다.",
+    ),
+    (
+        "replay.mismatches",
+        "{steps}단계를 다시 돌려봤습니다: {count}곳이 다릅니다.",
+    ),
+    ("replay.step", "{step}단계 (입력 {input}):"),
+    ("replay.expected", "  기록: {lines}"),
+    ("replay.actual", "  실제: {lines}"),
+    (
+        "bench.header",
+        "전략: {strategy}, 난이도: {difficulty} (시도 {attempts}번)",
+    ),
+    (
+        "bench.summary",
+        "판 수: {games}, 승리: {wins}, 패배: {losses}",
+    ),
+    ("bench.attempts", "평균 시도: {mean}, 최대 시도: {max}"),
+    ("tui.plausible", "가능한 범위: {min} - {max}"),
+    ("tui.attempts", "시도 횟수: {attempts}"),
+    ("tui.hints", "남은 힌트: {hints}"),
+    ("tui.score", "지금 맞히면 받는 점수: {score}"),
+    ("tui.time", "걸린 시간: {secs}초"),
+    ("tui.history", "기록"),
+    ("tui.ruled_out", "{guess}은(는) 이미 답이 될 수 없습니다."),
+    ("tui.help", "Enter: 추측, hint/history: 명령어, Esc: 끝내기"),
+    ("tui.press_key", "아무 키나 누르면 끝납니다."),
+    ("error.empty", "숫자를 입력하세요."),
+    (
+        "error.not_a_number",
+        "'{text}'은(는) 숫자가 아닙니다. 숫자나 quit, hint, history를 입력하세요.",
+    ),
+    (
+        "error.negative",
+        "{text}은(는) 음수입니다. 비밀 숫자는 양수입니다.",
+    ),
+    ("error.too_large", "{text}은(는) 너무 큽니다."),
+    (
+        "error.out_of_range",
+        "{min}부터 {max} 사이의 숫자를 입력하세요.",
+    ),
+    (
+        "error.wrong_length",
+        "{expected}자리 숫자를 입력하세요. ({got}자리를 입력했습니다)",
+    ),
+    (
+        "error.repeated_digit",
+        "숫자 {digit}이(가) 반복됩니다. 모든 자리의 숫자는 서로 달라야 합니다.",
+    ),
+    (
+        "error.not_an_answer",
+        "'{text}'은(는) 대답이 아닙니다. higher, lower, correct 중에 입력하세요.",
+    ),
+    (
+        "error.not_a_letter",
+        "'{text}'은(는) 글자나 단어가 아닙니다.",
+    ),
+];
+
+pub fn bundle(lang: Lang) -> Bundle {
+    match lang {
+        Lang::En => EN,
+        Lang::Ko => KO,
+    }
+}
+
+// 한 언어의 문장을 찾아주는 값. Copy라서 게임마다 하나씩 들고 다녀도 부담이 없다.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Messages {
+    lang: Lang,
+}
+
+impl Messages {
+    pub fn new(lang: Lang) -> Messages {
+        Messages { lang }
+    }
+
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    // 키에 해당하는 문장을 그대로 돌려준다.
+    // 번역이 빠진 키는 영어로, 영어에도 없으면 키 자체를 보여준다. (테스트에서 빠진 키를 잡아낸다)
+    pub fn get(&self, key: &'static str) -> &'static str {
+        lookup(bundle(self.lang), key)
+            .or_else(|| lookup(EN, key))
+            .unwrap_or(key)
+    }
+
+    // 문장 안의 {이름}을 args의 값으로 바꾼다.
+    // 문장을 한 번만 훑으면서 바꾸므로, 넣은 값 안에 {이름}이 있어도(예: 플레이어 이름) 다시 바꾸지 않는다.
+    // args에 없는 {이름}은 그대로 남긴다.
+    pub fn format(&self, key: &'static str, args: &[(&str, &dyn fmt::Display)]) -> String {
+        let mut rest = self.get(key);
+        let mut text = String::with_capacity(rest.len());
+        while let Some(start) = rest.find('{') {
+            text.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let value = after.find('}').and_then(|end| {
+                let name = &after[..end];
+                args.iter()
+                    .find(|(arg, _)| *arg == name)
+                    .map(|(_, value)| (value, end))
+            });
+            match value {
+                Some((value, end)) => {
+                    text.push_str(&value.to_string());
+                    rest = &after[end + 1..];
+                }
+                None => {
+                    text.push('{');
+                    rest = after;
+                }
+            }
+        }
+        text.push_str(rest);
+        text
+    }
+}
+
+fn lookup(bundle: Bundle, key: &str) -> Option<&'static str> {
+    bundle
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, text)| *text)
+}
@@ -0,0 +1,216 @@
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::engine::{GameEngine, GuessError};
+use crate::game::Outcome;
+use crate::input::{parse_command, parse_input, Command, Input};
+use crate::messages::Messages;
+
+// --json 모드: 사람이 읽는 문장 대신 한 줄에 JSON 객체 하나씩 주고받는다. (JSON Lines)
+//
+// 입력:  {"guess": 42}  또는  {"command": "hint"}   (quit, hint, history)
+// 출력:  {"event":"start","min":1,"max":100,"max_attempts":10,"hints":2}
+//        {"result":"too_small","attempt":3,"attempts_left":7}
+//        {"error":"out_of_range","message":"Please guess a number between 1 and 100."}
+//        {"event":"won","attempts":4}
+
+// 들어오는 요청. guess는 숫자와 문자열("42") 모두 받는다.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Request {
+    guess: Option<Value>,
+    command: Option<String>,
+}
+
+pub fn play_json<R: BufRead, W: Write>(
+    engine: &mut GameEngine,
+    mut input: R,
+    mut output: W,
+) -> io::Result<Outcome> {
+    let range = engine.range();
+    send(
+        &mut output,
+        json!({
+            "event": "start",
+            "min": range.min(),
+            "max": range.max(),
+            "max_attempts": engine.max_attempts(),
+            "hints": engine.hints_left(),
+        }),
+    )?;
+
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return finish(engine, &mut output);
+        }
+        // 빈 줄은 무시한다.
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let request: Request = match serde_json::from_str(&line) {
+            Ok(request) => request,
+            Err(e) => {
+                send_error(&mut output, "invalid_json", &e.to_string())?;
+                continue;
+            }
+        };
+
+        match (request.guess, request.command) {
+            (Some(guess), None) => {
+                if let Some(outcome) = handle_guess(engine, &guess, &mut output)? {
+                    return Ok(outcome);
+                }
+            }
+            (None, Some(command)) => match parse_command(&command) {
+                Some(Command::Quit) => return finish(engine, &mut output),
+                Some(Command::Hint) => match engine.use_hint() {
+                    Ok(narrowed) => send(
+                        &mut output,
+                        json!({
+                            "hint": { "min": narrowed.start(), "max": narrowed.end() },
+                            "hints_left": engine.hints_left(),
+                        }),
+                    )?,
+                    Err(_) => send_error(
+                        &mut output,
+                        "no_hints_left",
+                        Messages::new(engine.lang()).get("no_hints_left"),
+                    )?,
+                },
+                Some(Command::History) => {
+                    let history: Vec<Value> = engine
+                        .history()
+                        .iter()
+                        .map(|(guess, ordering)| {
+                            json!({ "guess": guess, "result": result_name(*ordering) })
+                        })
+                        .collect();
+                    send(&mut output, json!({ "history": history }))?;
+                }
+                None => send_error(
+                    &mut output,
+                    "unknown_command",
+                    &format!("unknown command: {}", command),
+                )?,
+            },
+            _ => send_error(
+                &mut output,
+                "invalid_request",
+                "expected exactly one of \"guess\" or \"command\"",
+            )?,
+        }
+    }
+}
+
+fn handle_guess<W: Write>(
+    engine: &mut GameEngine,
+    guess: &Value,
+    output: &mut W,
+) -> io::Result<Option<Outcome>> {
+    // 숫자든 문자열이든 텍스트로 바꿔서 사람용 모드와 같은 검증을 거친다.
+    let text = match guess {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    // message는 사람이 읽는 문장이므로 --lang을 따른다. error 이름은 언어와 상관없이 같다.
+    let messages = Messages::new(engine.lang());
+    let guess = match parse_input(&text, engine.range()) {
+        Ok(Input::Guess(guess)) => guess,
+        Ok(Input::Command(_)) => {
+            send_error(
+                output,
+                "not_a_number",
+                "use {\"command\": ...} for commands",
+            )?;
+            return Ok(None);
+        }
+        Err(e) => {
+            send_error(output, e.kind(), &e.message(&messages))?;
+            return Ok(None);
+        }
+    };
+
+    let ordering = match engine.guess(guess) {
+        Ok(ordering) => ordering,
+        Err(GuessError::OutOfRange { range, .. }) => {
+            let message = messages.format(
+                "error.out_of_range",
+                &[("min", &range.min()), ("max", &range.max())],
+            );
+            send_error(output, "out_of_range", &message)?;
+            return Ok(None);
+        }
+        // 게임이 끝나면 바로 반환하지만, 이미 끝난 엔진을 넘겨받았을 수도 있다.
+        // 에러를 알리고 마지막 이벤트로 마무리한다.
+        Err(GuessError::GameOver) => {
+            send_error(output, "game_over", messages.get("game_over"))?;
+            return finish(engine, output).map(Some);
+        }
+    };
+
+    let mut event = json!({
+        "result": result_name(ordering),
+        "guess": guess,
+        "attempt": engine.attempts(),
+    });
+    if let Some(left) = engine.attempts_left() {
+        event["attempts_left"] = json!(left);
+    }
+    if engine.is_hot_cold() && ordering != Ordering::Equal {
+        event["proximity"] = json!(engine.proximity(guess).as_str());
+    }
+    send(output, event)?;
+
+    if engine.is_over() {
+        return finish(engine, output).map(Some);
+    }
+    Ok(None)
+}
+
+// 마지막 이벤트를 보내고 결과를 반환한다.
+// 게임이 끝나지 않았는데 호출되면(EOF, quit) 그만둔 것으로 본다.
+fn finish<W: Write>(engine: &GameEngine, output: &mut W) -> io::Result<Outcome> {
+    let attempts = engine.attempts();
+    let (outcome, event) = match engine.reveal() {
+        _ if engine.is_won() => (
+            Outcome::Won { attempts },
+            json!({ "event": "won", "attempts": attempts }),
+        ),
+        Some(secret_number) => (
+            Outcome::Lost {
+                attempts,
+                secret: secret_number.to_string(),
+            },
+            json!({ "event": "lost", "attempts": attempts, "secret": secret_number }),
+        ),
+        None => (
+            Outcome::Abandoned { attempts },
+            json!({ "event": "abandoned", "attempts": attempts }),
+        ),
+    };
+    send(output, event)?;
+    Ok(outcome)
+}
+
+fn result_name(ordering: Ordering) -> &'static str {
+    match ordering {
+        Ordering::Less => "too_small",
+        Ordering::Greater => "too_big",
+        Ordering::Equal => "correct",
+    }
+}
+
+fn send_error<W: Write>(output: &mut W, kind: &str, message: &str) -> io::Result<()> {
+    send(output, json!({ "error": kind, "message": message }))
+}
+
+// 한 줄에 하나씩 쓰고 바로 flush한다. 다른 프로그램이 파이프로 읽고 있을 수 있기 때문이다.
+pub fn send<W: Write>(output: &mut W, event: Value) -> io::Result<()> {
+    writeln!(output, "{}", event)?;
+    output.flush()
+}
@@ -0,0 +1,354 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{self, Write};
+use std::time::Instant;
+
+use crate::bulls;
+use crate::cli::GameMode;
+use crate::difficulty::GuessRange;
+use crate::game::{step, Game, Outcome};
+use crate::messages::{Lang, Messages};
+use crate::session::SessionConfig;
+
+// 세션 기록 파일의 첫 줄. 형식이 바뀌면 숫자를 올린다.
+const MAGIC: &str = "guessing_game-log\t1";
+
+// 세션 기록은 한 줄에 레코드 하나씩, 탭으로 구분된 텍스트 파일이다.
+//
+//   guessing_game-log  1
+//   seed               42
+//   ...                (나머지 설정)
+//   input   <ms>  <입력한 줄 그대로>
+//   guess   <ms>  <받아들여진 추측>
+//   feedback <ms> <출력된 한 줄>
+//   outcome <ms>  <결과>
+//
+// <ms>는 세션을 시작한 뒤로 지난 시간(밀리초)이다.
+
+// 입력 한 줄과 그에 대한 반응
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub at_ms: u64,
+    pub input: String,
+    pub guess: Option<String>,
+    pub feedback: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionLog {
+    pub config: SessionConfig,
+    pub events: Vec<Event>,
+    pub outcome: Option<String>,
+}
+
+// 게임을 하면서 바로바로 파일에 기록한다. 중간에 프로그램이 죽어도 거기까지는 남는다.
+pub struct Recorder<W: Write> {
+    writer: W,
+    started: Instant,
+    attempts: u32,
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(mut writer: W, config: &SessionConfig) -> io::Result<Recorder<W>> {
+        writeln!(writer, "{}", MAGIC)?;
+        writeln!(writer, "seed\t{}", config.seed)?;
+        writeln!(writer, "game\t{}", config.game)?;
+        writeln!(
+            writer,
+            "range\t{}\t{}",
+            config.range.min(),
+            config.range.max()
+        )?;
+        writeln!(writer, "hot_cold\t{}", config.hot_cold)?;
+        writeln!(writer, "digits\t{}", config.digits)?;
+        writeln!(writer, "max_attempts\t{}", config.max_attempts)?;
+        writeln!(writer, "hints\t{}", config.hints)?;
+        writeln!(writer, "label\t{}", config.label)?;
+        writeln!(writer, "lang\t{}", config.lang)?;
+        if config.game == GameMode::Hangman {
+            writeln!(writer, "word\t{}", config.word)?;
+        }
+        writer.flush()?;
+        Ok(Recorder {
+            writer,
+            started: Instant::now(),
+            attempts: 0,
+        })
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        self.started.elapsed().as_millis() as u64
+    }
+
+    // play_with의 on_step으로 넘겨서 사용한다.
+    pub fn record_step<G: Game + ?Sized>(
+        &mut self,
+        game: &G,
+        line: &str,
+        feedback: &[u8],
+    ) -> io::Result<()> {
+        let at = self.elapsed_ms();
+        writeln!(self.writer, "input\t{}\t{}", at, strip_newline(line))?;
+        // 시도 횟수가 늘었을 때만 추측이 받아들여진 것이다.
+        if game.attempts() > self.attempts {
+            self.attempts = game.attempts();
+            if let Some(guess) = game.last_guess() {
+                writeln!(self.writer, "guess\t{}\t{}", at, guess)?;
+            }
+        }
+        for line in String::from_utf8_lossy(feedback).lines() {
+            writeln!(self.writer, "feedback\t{}\t{}", at, line)?;
+        }
+        self.writer.flush()
+    }
+
+    pub fn record_outcome(&mut self, outcome: &Outcome) -> io::Result<()> {
+        let at = self.elapsed_ms();
+        writeln!(self.writer, "outcome\t{}\t{:?}", at, outcome)?;
+        self.writer.flush()
+    }
+}
+
+// read_line으로 읽은 줄의 끝에 붙은 줄바꿈만 떼어낸다. 나머지 공백은 그대로 남긴다.
+fn strip_newline(line: &str) -> &str {
+    line.strip_suffix('\n').unwrap_or(line)
+}
+
+impl SessionLog {
+    pub fn parse(text: &str) -> Result<SessionLog, String> {
+        // lines()는 \r도 떼어버리므로 \n으로만 나눈다.
+        let mut lines = text.split('\n').enumerate().filter(|(_, l)| !l.is_empty());
+
+        match lines.next() {
+            Some((_, line)) if line == MAGIC => {}
+            _ => return Err("not a guessing_game session log".to_string()),
+        }
+
+        let mut settings = Vec::new();
+        let mut events: Vec<Event> = Vec::new();
+        let mut outcome = None;
+
+        for (index, line) in lines {
+            let number = index + 1;
+            let mut fields = line.splitn(3, '\t');
+            let key = fields.next().unwrap_or("");
+            match key {
+                "input" | "guess" | "feedback" | "outcome" => {
+                    let at_ms = fields
+                        .next()
+                        .and_then(|ms| ms.parse().ok())
+                        .ok_or_else(|| format!("line {}: missing timestamp", number))?;
+                    let value = fields.next().unwrap_or("").to_string();
+                    match key {
+                        "input" => events.push(Event {
+                            at_ms,
+                            input: value,
+                            guess: None,
+                            feedback: Vec::new(),
+                        }),
+                        "outcome" => outcome = Some(value),
+                        _ => {
+                            let event = events.last_mut().ok_or_else(|| {
+                                format!("line {}: {} before any input", number, key)
+                            })?;
+                            if key == "guess" {
+                                event.guess = Some(value);
+                            } else {
+                                event.feedback.push(value);
+                            }
+                        }
+                    }
+                }
+                _ => settings.push((number, key, line[key.len()..].trim_start_matches('\t'))),
+            }
+        }
+
+        Ok(SessionLog {
+            config: parse_config(&settings)?,
+            events,
+            outcome,
+        })
+    }
+}
+
+fn parse_config(settings: &[(usize, &str, &str)]) -> Result<SessionConfig, String> {
+    let get = |name: &str| -> Result<&str, String> {
+        settings
+            .iter()
+            .find(|(_, key, _)| *key == name)
+            .map(|(_, _, value)| *value)
+            .ok_or_else(|| format!("missing setting: {}", name))
+    };
+    let number = |name: &str| -> Result<u64, String> {
+        let value = get(name)?;
+        value
+            .parse()
+            .map_err(|_| format!("invalid {}: {}", name, value))
+    };
+    // 기록 파일은 손으로 고칠 수도 있으므로, 게임을 만들 때 panic하지 않도록 여기서 모두 확인한다.
+    let small = |name: &str| -> Result<u32, String> {
+        let value = number(name)?;
+        u32::try_from(value).map_err(|_| format!("invalid {}: {}", name, value))
+    };
+    let digits = number("digits")?;
+    let digits = usize::try_from(digits)
+        .ok()
+        .filter(|digits| (bulls::MIN_DIGITS..=bulls::MAX_DIGITS).contains(digits))
+        .ok_or_else(|| {
+            format!(
+                "digits must be between {} and {}, got {}",
+                bulls::MIN_DIGITS,
+                bulls::MAX_DIGITS,
+                digits
+            )
+        })?;
+
+    let range = get("range")?;
+    let mut bounds = range.split('\t').map(|n| n.parse::<u32>());
+    let range = match (bounds.next(), bounds.next()) {
+        (Some(Ok(min)), Some(Ok(max))) => GuessRange::new(min, max)?,
+        _ => return Err(format!("invalid range: {}", range)),
+    };
+
+    let game = get("game")?.parse()?;
+    Ok(SessionConfig {
+        seed: number("seed")?,
+        game,
+        range,
+        hot_cold: get("hot_cold")? == "true",
+        digits,
+        max_attempts: small("max_attempts")?,
+        hints: small("hints")?,
+        label: get("label")?.to_string(),
+        // 언어 설정이 생기기 전의 기록은 영어로 진행된 것이다.
+        lang: match get("lang") {
+            Ok(lang) => lang.parse()?,
+            Err(_) => Lang::default(),
+        },
+        word: if game == GameMode::Hangman {
+            let word = get("word")?;
+            if !word.chars().any(char::is_alphabetic) {
+                return Err(format!("invalid word: '{}' has no letters to guess", word));
+            }
+            word.to_string()
+        } else {
+            String::new()
+        },
+    })
+}
+
+// 기록과 다시 돌려본 결과가 다른 곳
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    // 몇 번째 입력인지 (1부터)
+    pub step: usize,
+    pub input: String,
+    pub expected: Vec<String>,
+    pub actual: Vec<String>,
+}
+
+impl Mismatch {
+    // 고른 언어로 여러 줄의 설명을 만든다.
+    pub fn message(&self, messages: &Messages) -> String {
+        let lines = [
+            messages.format(
+                "replay.step",
+                &[
+                    ("step", &self.step),
+                    ("input", &format!("{:?}", self.input)),
+                ],
+            ),
+            messages.format(
+                "replay.expected",
+                &[("lines", &format!("{:?}", self.expected))],
+            ),
+            messages.format("replay.actual", &[("lines", &format!("{:?}", self.actual))]),
+        ];
+        lines.join("\n")
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message(&Messages::default()))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ReplayReport {
+    pub steps: usize,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl ReplayReport {
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+// 기록된 설정으로 게임을 다시 만들고, 기록된 입력을 차례로 넣어본다.
+// 추측과 출력이 기록과 같은지 확인한다.
+pub fn replay(log: &SessionLog) -> io::Result<ReplayReport> {
+    let mut game = log.config.build();
+    let mut mismatches = Vec::new();
+    let mut outcome = None;
+
+    for (i, event) in log.events.iter().enumerate() {
+        let before = game.attempts();
+        let mut feedback = Vec::new();
+        let result = step(&mut *game, &event.input, &mut feedback)?;
+
+        let mut actual: Vec<String> = String::from_utf8_lossy(&feedback)
+            .lines()
+            .map(String::from)
+            .collect();
+        let mut expected = event.feedback.clone();
+        let guess = if game.attempts() > before {
+            game.last_guess()
+        } else {
+            None
+        };
+        if guess != event.guess {
+            expected.insert(0, format!("guess {:?}", event.guess));
+            actual.insert(0, format!("guess {:?}", guess));
+        }
+        if expected != actual {
+            mismatches.push(Mismatch {
+                step: i + 1,
+                input: event.input.clone(),
+                expected,
+                actual,
+            });
+        }
+
+        if let Some(result) = result {
+            outcome = Some(format!("{:?}", result));
+            break;
+        }
+    }
+
+    // 기록이 EOF로 끝났다면 마지막 결과는 Abandoned다.
+    let outcome = outcome.unwrap_or_else(|| {
+        format!(
+            "{:?}",
+            Outcome::Abandoned {
+                attempts: game.attempts()
+            }
+        )
+    });
+    if let Some(expected) = &log.outcome {
+        if *expected != outcome {
+            mismatches.push(Mismatch {
+                step: log.events.len(),
+                input: String::from("(end of session)"),
+                expected: vec![expected.clone()],
+                actual: vec![outcome],
+            });
+        }
+    }
+
+    Ok(ReplayReport {
+        steps: log.events.len(),
+        mismatches,
+    })
+}
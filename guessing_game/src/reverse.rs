@@ -0,0 +1,155 @@
+use std::cmp::Ordering;
+
+use crate::difficulty::GuessRange;
+use crate::messages::Lang;
+use crate::solver::{BinarySearch, Strategy};
+
+// 거꾸로 하는 숫자 맞추기: 사람이 숫자를 하나 생각하고, 컴퓨터가 맞춘다.
+// 사람은 컴퓨터의 추측에 higher/lower/correct로 대답한다.
+// 대답은 "생각한 숫자가 추측과 비교해서 어떤지"이므로 secret.cmp(&guess)와 같은 Ordering이 된다.
+//   higher  -> Greater
+//   lower   -> Less
+//   correct -> Equal
+
+// 숫자가 있을 수 있는 범위의 한쪽 끝이 어디서 왔는지
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bound {
+    // 처음 정한 범위의 끝
+    Range(u32),
+    // step번째(1부터) 대답. guess에 대해 higher/lower라고 했다.
+    Answer { step: usize, guess: u32 },
+}
+
+// 대답들을 모두 만족하는 숫자가 없을 때, 서로 부딪히는 두 끝
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contradiction {
+    // "이것보다 크다"
+    pub above: Bound,
+    // "이것보다 작다"
+    pub below: Bound,
+}
+
+pub struct Guesser {
+    range: GuessRange,
+    strategy: Box<dyn Strategy>,
+    // 대답들로 좁혀진 범위와, 각 끝을 정한 대답
+    low: (u32, Bound),
+    high: (u32, Bound),
+    // 지금 대답을 기다리고 있는 추측
+    current: u32,
+    history: Vec<(u32, Ordering)>,
+    found: bool,
+    contradiction: Option<Contradiction>,
+    lang: Lang,
+}
+
+impl Guesser {
+    pub fn new(range: GuessRange) -> Guesser {
+        Guesser::with_strategy(range, Box::new(BinarySearch))
+    }
+
+    pub fn with_strategy(range: GuessRange, mut strategy: Box<dyn Strategy>) -> Guesser {
+        let current = strategy.next_guess(range.min(), range.max());
+        Guesser {
+            range,
+            strategy,
+            low: (range.min(), Bound::Range(range.min())),
+            high: (range.max(), Bound::Range(range.max())),
+            current,
+            history: Vec::new(),
+            found: false,
+            contradiction: None,
+            lang: Lang::default(),
+        }
+    }
+
+    pub fn with_lang(mut self, lang: Lang) -> Guesser {
+        self.lang = lang;
+        self
+    }
+
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    pub fn range(&self) -> GuessRange {
+        self.range
+    }
+
+    // 대답을 기다리고 있는 추측. 게임이 끝났으면 None이다.
+    pub fn current_guess(&self) -> Option<u32> {
+        if self.is_over() {
+            None
+        } else {
+            Some(self.current)
+        }
+    }
+
+    // 지금 추측에 대한 대답을 받는다.
+    // 아직 못 맞췄으면 다음 추측을, 맞췄으면 None을 돌려준다.
+    // 대답들을 모두 만족하는 숫자가 없어지면 Err로 어떤 대답들이 부딪히는지 알려준다.
+    pub fn answer(&mut self, reply: Ordering) -> Result<Option<u32>, Contradiction> {
+        if let Some(contradiction) = &self.contradiction {
+            return Err(contradiction.clone());
+        }
+        if self.found {
+            return Ok(None);
+        }
+
+        let guess = self.current;
+        self.history.push((guess, reply));
+        let bound = Bound::Answer {
+            step: self.history.len(),
+            guess,
+        };
+        // 추측은 항상 low..=high 안에 있으므로, 끝에 있는 숫자보다 더 바깥이라고 하면 범위가 비어버린다.
+        match reply {
+            Ordering::Equal => {
+                self.found = true;
+                return Ok(None);
+            }
+            Ordering::Greater if guess >= self.high.0 => {
+                return Err(self.contradict(bound, self.high.1));
+            }
+            Ordering::Less if guess <= self.low.0 => {
+                return Err(self.contradict(self.low.1, bound));
+            }
+            Ordering::Greater => self.low = (guess + 1, bound),
+            Ordering::Less => self.high = (guess - 1, bound),
+        }
+
+        self.current = self.strategy.next_guess(self.low.0, self.high.0);
+        Ok(Some(self.current))
+    }
+
+    fn contradict(&mut self, above: Bound, below: Bound) -> Contradiction {
+        let contradiction = Contradiction { above, below };
+        self.contradiction = Some(contradiction.clone());
+        contradiction
+    }
+
+    // 지금까지의 대답으로 봤을 때 사람이 생각한 숫자가 있을 수 있는 범위
+    pub fn plausible_range(&self) -> (u32, u32) {
+        (self.low.0, self.high.0)
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.history.len() as u32
+    }
+
+    pub fn history(&self) -> &[(u32, Ordering)] {
+        &self.history
+    }
+
+    pub fn is_found(&self) -> bool {
+        self.found
+    }
+
+    pub fn contradiction(&self) -> Option<&Contradiction> {
+        self.contradiction.as_ref()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.found || self.contradiction.is_some()
+    }
+}
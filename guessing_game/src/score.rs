@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use crate::bulls::BullsCowsEngine;
+use crate::engine::GameEngine;
+use crate::hangman::HangmanEngine;
+
+// 점수 계산에 필요한 한 판의 기록
+#[derive(Debug, Clone, PartialEq)]
+pub struct Performance {
+    // 게임의 어려운 정도에 따른 기본 점수
+    pub base: u32,
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub hints_used: u32,
+    pub hot_cold: bool,
+    pub elapsed: Duration,
+}
+
+impl Performance {
+    pub fn from_engine(engine: &GameEngine, elapsed: Duration) -> Performance {
+        Performance {
+            base: engine.range().binary_search_steps() * 100,
+            attempts: engine.attempts(),
+            max_attempts: engine.max_attempts().unwrap_or_else(|| engine.attempts()),
+            hints_used: engine.hints_used(),
+            hot_cold: engine.is_hot_cold(),
+            elapsed,
+        }
+    }
+
+    // 자릿수가 많을수록 기본 점수가 높다.
+    pub fn from_bulls_cows(engine: &BullsCowsEngine, elapsed: Duration) -> Performance {
+        Performance {
+            base: engine.digits() as u32 * 150,
+            attempts: engine.attempts(),
+            max_attempts: engine.max_attempts().unwrap_or_else(|| engine.attempts()),
+            hints_used: engine.hints_used(),
+            hot_cold: false,
+            elapsed,
+        }
+    }
+
+    // 행맨은 맞힌 글자가 아니라 틀린 횟수(잃은 목숨)로 시도 횟수를 센다.
+    // 서로 다른 글자가 많은 단어일수록 기본 점수가 높다.
+    pub fn from_hangman(engine: &HangmanEngine, elapsed: Duration) -> Performance {
+        Performance {
+            base: engine.unique_letters() as u32 * 100,
+            attempts: engine.max_lives() - engine.lives(),
+            max_attempts: engine.max_lives(),
+            hints_used: engine.hints_used(),
+            hot_cold: false,
+            elapsed,
+        }
+    }
+}
+
+// 이긴 판의 점수를 계산한다. 진 판은 점수가 없다.
+// - 어려운 게임일수록(범위가 넓거나 자릿수가 많을수록) 기본 점수가 높다.
+// - 남은 시도 횟수마다 보너스가 붙는다.
+// - hint는 한 번 쓸 때마다 100점, hot/cold 모드는 기본 점수의 1/4이 깎인다.
+// - 오래 걸릴수록 1초에 2점씩 깎인다. 단, 0점 밑으로는 내려가지 않는다.
+pub fn score(performance: &Performance) -> u32 {
+    let mut base = performance.base;
+    if performance.hot_cold {
+        base -= base / 4;
+    }
+    let bonus = performance
+        .max_attempts
+        .saturating_sub(performance.attempts)
+        * 50;
+    let hint_penalty = performance.hints_used * 100;
+    let time_penalty = performance.elapsed.as_secs().min(u64::from(u32::MAX)) as u32 * 2;
+    (base + bonus)
+        .saturating_sub(hint_penalty)
+        .saturating_sub(time_penalty)
+}
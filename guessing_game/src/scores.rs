@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use errors::fsutil;
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Messages;
+
+// 상위 몇 개의 기록만 남길지
+pub const MAX_ENTRIES: usize = 10;
+
+// 점수표의 기록 하나.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreEntry {
+    pub name: String,
+    pub score: u32,
+    pub attempts: u32,
+    // 예전 점수표에는 없던 필드라서, 없으면 0으로 읽는다.
+    #[serde(default)]
+    pub hints_used: u32,
+    pub difficulty: String,
+    pub elapsed_secs: u64,
+    // UNIX epoch 기준 초
+    pub recorded_at: u64,
+}
+
+// hot-seat 대전에서 쌓인 한 사람의 기록
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub rounds_played: u32,
+    pub rounds_won: u32,
+    pub attempts: u32,
+}
+
+impl PlayerStats {
+    // 이긴 라운드의 비율(%). 아직 한 라운드도 안 했으면 0이다.
+    pub fn win_rate(&self) -> u32 {
+        (self.rounds_won * 100)
+            .checked_div(self.rounds_played)
+            .unwrap_or(0)
+    }
+}
+
+// 점수가 높은 순서로 정렬된 점수표.
+// hot-seat 대전의 사람별 기록도 같은 파일에 함께 저장한다.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreTable {
+    entries: Vec<ScoreEntry>,
+    // 예전 점수표에는 없던 필드라서, 없으면 비어있는 것으로 읽는다.
+    #[serde(default)]
+    players: BTreeMap<String, PlayerStats>,
+}
+
+// 기본 저장 위치: ~/.guessing_game/scores.json
+pub fn default_path() -> PathBuf {
+    let home = env::var_os("HOME").unwrap_or_else(|| ".".into());
+    PathBuf::from(home)
+        .join(".guessing_game")
+        .join("scores.json")
+}
+
+impl ScoreTable {
+    // 파일이 아직 없으면 비어있는 점수표로 시작한다.
+    pub fn load(path: &Path) -> io::Result<ScoreTable> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(ref e) if e.kind() == ErrorKind::NotFound => return Ok(ScoreTable::default()),
+            Err(e) => return Err(e),
+        };
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+
+    // fsutil::write_atomic으로 같은 디렉터리의 임시 파일에 먼저 쓴 다음 rename으로 바꿔치기한다.
+    // 쓰는 도중에 죽어도 기존 점수표가 깨지지 않고, 임시 파일 이름이 쓰는 쪽마다 달라서
+    // 동시에 저장해도 서로의 임시 파일을 덮어쓰지 않는다.
+    // 다만 읽고-고치고-저장하는 사이를 잠그지는 않으므로, 나중에 저장한 쪽의 내용만 남는다.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        fsutil::write_atomic(path, json.as_bytes()).map_err(|e| io::Error::new(e.kind(), e))
+    }
+
+    // 기록을 추가하고, 점수표에 들어갔다면 순위(1부터)를 반환한다.
+    pub fn insert(&mut self, entry: ScoreEntry) -> Option<usize> {
+        // 같은 점수라면 먼저 세운 기록이 위에 있도록 한다.
+        let position = self
+            .entries
+            .iter()
+            .position(|e| e.score < entry.score)
+            .unwrap_or(self.entries.len());
+        if position >= MAX_ENTRIES {
+            return None;
+        }
+        self.entries.insert(position, entry);
+        self.entries.truncate(MAX_ENTRIES);
+        Some(position + 1)
+    }
+
+    pub fn entries(&self) -> &[ScoreEntry] {
+        &self.entries
+    }
+
+    // 한 사람의 라운드 하나를 기록하고, 지금까지 쌓인 기록을 돌려준다.
+    pub fn record_round(&mut self, name: &str, won: bool, attempts: u32) -> &PlayerStats {
+        let stats = self.players.entry(name.to_string()).or_default();
+        stats.rounds_played += 1;
+        if won {
+            stats.rounds_won += 1;
+        }
+        stats.attempts += attempts;
+        stats
+    }
+
+    pub fn player(&self, name: &str) -> Option<&PlayerStats> {
+        self.players.get(name)
+    }
+
+    pub fn print<W: Write>(&self, messages: &Messages, mut output: W) -> io::Result<()> {
+        if self.entries.is_empty() {
+            writeln!(output, "{}", messages.get("scores.empty"))?;
+        } else {
+            self.print_entries(messages, &mut output)?;
+        }
+        if !self.players.is_empty() {
+            writeln!(output)?;
+            writeln!(output, "{}", messages.get("scores.players_header"))?;
+            for (name, stats) in &self.players {
+                writeln!(
+                    output,
+                    "{:<16} {:>7} {:>6} {:>8}% {:>9}",
+                    name,
+                    stats.rounds_played,
+                    stats.rounds_won,
+                    stats.win_rate(),
+                    stats.attempts
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn print_entries<W: Write>(&self, messages: &Messages, mut output: W) -> io::Result<()> {
+        writeln!(output, "{}", messages.get("scores.header"))?;
+        for (i, e) in self.entries.iter().enumerate() {
+            writeln!(
+                output,
+                "{:>4}  {:<16} {:>6} {:>8} {:>6}s  {}",
+                i + 1,
+                e.name,
+                e.score,
+                e.attempts,
+                e.elapsed_secs,
+                e.difficulty
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+// Rng는 random number generator 가 구현된 메서드다.
+
+// 비밀 숫자를 뽑아주는 역할을 trait로 분리했다.
+// 실제 게임에서는 rand의 Rng를, 테스트에서는 정해진 값을 돌려주는 FixedSequence를 쓸 수 있다.
+pub trait SecretSource {
+    // low 이상 high 이하(inclusive)의 숫자를 하나 뽑는다.
+    fn next_secret(&mut self, low: u32, high: u32) -> u32;
+}
+
+// Rng가 구현된 모든 타입에 SecretSource를 구현해준다. (blanket implementation)
+// thread_rng()도, 시드를 준 StdRng도 그대로 SecretSource로 사용할 수 있다.
+impl<R: Rng> SecretSource for R {
+    fn next_secret(&mut self, low: u32, high: u32) -> u32 {
+        // gen_range의 두번째 인자는 포함되지 않으므로 1을 더해준다.
+        // u32::MAX를 넘지 않도록 u64로 계산한다.
+        self.gen_range(u64::from(low), u64::from(high) + 1) as u32
+    }
+}
+
+// 같은 시드를 주면 항상 같은 순서의 비밀 숫자가 나온다.
+pub fn seeded(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+// 테스트용으로, 미리 정해둔 숫자들을 순서대로(끝나면 처음부터 다시) 돌려준다.
+// 범위를 벗어난 값은 범위 안으로 맞춰준다.
+pub struct FixedSequence {
+    values: Vec<u32>,
+    next: usize,
+}
+
+impl FixedSequence {
+    pub fn new(values: Vec<u32>) -> FixedSequence {
+        if values.is_empty() {
+            panic!("FixedSequence needs at least one value.");
+        }
+        FixedSequence { values, next: 0 }
+    }
+}
+
+impl SecretSource for FixedSequence {
+    fn next_secret(&mut self, low: u32, high: u32) -> u32 {
+        let value = self.values[self.next];
+        self.next = (self.next + 1) % self.values.len();
+        value.max(low).min(high)
+    }
+}
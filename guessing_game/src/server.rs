@@ -0,0 +1,252 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::difficulty::GuessRange;
+use crate::engine::{GameEngine, GuessError};
+use crate::input::{parse_input, Command, Input};
+use crate::messages::{Lang, Messages};
+use crate::secret::SecretSource;
+
+// accept가 실패하면 잠깐 쉬었다가 다시 받는다.
+// EMFILE처럼 곧바로 다시 실패하는 에러에서 CPU를 다 쓰며 에러 메시지만 쏟아내지 않도록 한다.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+// 여러 클라이언트가 TCP로 접속해서 같은 비밀 숫자를 맞추는 서버.
+// 한 줄에 하나씩 추측을 보내면 Too small! / Too big! / You win! 중 하나로 답한다.
+// 가장 먼저 맞춘 사람이 그 라운드를 이기고, 바로 새로운 비밀 숫자로 다음 라운드가 시작된다.
+pub struct Server {
+    listener: TcpListener,
+    shared: Arc<Mutex<Shared>>,
+}
+
+// 모든 클라이언트 스레드가 같이 쓰는 상태. Mutex로 감싸서 한번에 한 스레드만 접근한다.
+// 추측을 처리하는 동안 lock을 잡고 있으므로, 동시에 맞춰도 승자는 한 명뿐이다.
+struct Shared {
+    range: GuessRange,
+    source: Box<dyn SecretSource + Send>,
+    engine: GameEngine,
+    round: u32,
+    messages: Messages,
+    // 각 클라이언트에게 보낼 메시지의 큐. 클라이언트마다 쓰기 전용 스레드가 하나씩 있어서 큐에서 꺼내 보낸다.
+    // lock을 잡은 채로 큐에 넣기만 하므로 메시지는 일어난 순서대로 쌓이고,
+    // 느린 클라이언트 하나에 쓰느라 다른 클라이언트들이 기다리지 않는다.
+    clients: HashMap<usize, Sender<String>>,
+}
+
+impl Server {
+    pub fn bind<A: ToSocketAddrs>(
+        addr: A,
+        range: GuessRange,
+        mut source: Box<dyn SecretSource + Send>,
+    ) -> io::Result<Server> {
+        let listener = TcpListener::bind(addr)?;
+        let engine = GameEngine::new(source.next_secret(range.min(), range.max()), range);
+        let shared = Shared {
+            range,
+            source,
+            engine,
+            round: 1,
+            messages: Messages::default(),
+            clients: HashMap::new(),
+        };
+        Ok(Server {
+            listener,
+            shared: Arc::new(Mutex::new(shared)),
+        })
+    }
+
+    // 클라이언트에게 보내는 문장의 언어. 모든 클라이언트가 같은 언어를 쓴다.
+    pub fn with_lang(self, lang: Lang) -> Server {
+        self.shared.lock().unwrap().messages = Messages::new(lang);
+        self
+    }
+
+    // 포트를 0으로 bind하면 OS가 빈 포트를 골라주므로, 실제 주소는 여기서 확인한다.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    // 접속이 들어올 때마다 스레드를 하나씩 만들어 처리한다.
+    pub fn run(self) -> io::Result<()> {
+        for (id, stream) in (1..).zip(self.listener.incoming()) {
+            // accept 한 번이 실패했다고(ECONNABORTED, EMFILE 등) 다른 플레이어들의 게임까지 멈추지는 않는다.
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    eprintln!("Failed to accept a connection: {}", e);
+                    thread::sleep(ACCEPT_RETRY_DELAY);
+                    continue;
+                }
+            };
+            let shared = Arc::clone(&self.shared);
+            thread::spawn(move || {
+                // 클라이언트 하나의 에러가 서버 전체를 멈추게 하지는 않는다.
+                let _ = handle_client(id, stream, shared);
+            });
+        }
+        Ok(())
+    }
+}
+
+fn handle_client(id: usize, stream: TcpStream, shared: Arc<Mutex<Shared>>) -> io::Result<()> {
+    let reader = BufReader::new(stream.try_clone()?);
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || write_messages(stream, receiver.into_iter()));
+    {
+        let mut shared = shared.lock().unwrap();
+        shared.clients.insert(id, sender);
+        let welcome = shared.messages.format(
+            "server.welcome",
+            &[
+                ("id", &id),
+                ("round", &shared.round),
+                ("min", &shared.range.min()),
+                ("max", &shared.range.max()),
+            ],
+        );
+        shared.send(id, &welcome);
+        let joined = shared.messages.format("server.joined", &[("id", &id)]);
+        shared.broadcast(&joined, Some(id));
+    }
+
+    // 읽다가 에러가 나거나 연결이 끊기면(lines()가 끝나면) 클라이언트를 정리한다.
+    for line in reader.lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        let mut shared = shared.lock().unwrap();
+        if !shared.handle_line(id, &line) {
+            break;
+        }
+    }
+
+    let mut shared = shared.lock().unwrap();
+    // 큐를 닫으면 쓰기 스레드가 남은 메시지를 모두 보내고 끝난다.
+    shared.clients.remove(&id);
+    let left = shared.messages.format("server.left", &[("id", &id)]);
+    shared.broadcast(&left, None);
+    Ok(())
+}
+
+// 클라이언트 하나의 쓰기 스레드. 이 스트림에 쓰는 것은 이 스레드뿐이라서 줄이 섞이지 않는다.
+// 쓰기에 실패하면 연결이 끊긴 것이므로 그만둔다. 그 뒤로 큐에 넣으려 하면 send가 실패해서 목록에서 빠진다.
+fn write_messages<I: Iterator<Item = String>>(mut stream: TcpStream, messages: I) {
+    for message in messages {
+        if writeln!(stream, "{}", message).is_err() {
+            break;
+        }
+    }
+}
+
+impl Shared {
+    // 한 줄을 처리한다. 연결을 끊어야 하면 false를 반환한다.
+    fn handle_line(&mut self, id: usize, line: &str) -> bool {
+        let messages = self.messages;
+        let guess = match parse_input(line, self.range) {
+            Ok(Input::Guess(guess)) => guess,
+            Ok(Input::Command(Command::Quit)) => {
+                self.send(id, messages.get("bye"));
+                return false;
+            }
+            Ok(Input::Command(Command::Hint)) => {
+                let plausible = self.engine.plausible_range();
+                let hint = messages.format(
+                    "server.hint",
+                    &[("min", plausible.start()), ("max", plausible.end())],
+                );
+                self.send(id, &hint);
+                return true;
+            }
+            Ok(Input::Command(Command::History)) => {
+                let guesses: Vec<String> = self
+                    .engine
+                    .history()
+                    .iter()
+                    .map(|(guess, _)| guess.to_string())
+                    .collect();
+                let history =
+                    messages.format("server.history", &[("guesses", &guesses.join(", "))]);
+                self.send(id, &history);
+                return true;
+            }
+            Err(e) => {
+                self.send(id, &e.message(&messages));
+                return true;
+            }
+        };
+
+        match self.engine.guess(guess) {
+            Ok(Ordering::Less) => self.send(id, messages.get("number.too_small")),
+            Ok(Ordering::Greater) => self.send(id, messages.get("number.too_big")),
+            Ok(Ordering::Equal) => {
+                self.send(id, messages.get("win"));
+                let message = messages.format(
+                    "server.won",
+                    &[("id", &id), ("guess", &guess), ("round", &self.round)],
+                );
+                self.broadcast(&message, Some(id));
+                self.new_round();
+            }
+            Err(GuessError::OutOfRange { range, .. }) => {
+                let message = messages.format(
+                    "error.out_of_range",
+                    &[("min", &range.min()), ("max", &range.max())],
+                );
+                self.send(id, &message)
+            }
+            // 맞추는 즉시 새 라운드를 시작하므로 보통은 일어나지 않는다.
+            // 그래도 끝난 라운드에 추측이 들어오면 알려주고 새 라운드를 시작한다.
+            Err(GuessError::GameOver) => {
+                self.send(id, messages.get("server.round_over"));
+                self.new_round();
+            }
+        }
+        true
+    }
+
+    fn new_round(&mut self) {
+        let secret_number = self.source.next_secret(self.range.min(), self.range.max());
+        self.engine = GameEngine::new(secret_number, self.range);
+        self.round += 1;
+        let message = self.messages.format(
+            "server.round",
+            &[
+                ("round", &self.round),
+                ("min", &self.range.min()),
+                ("max", &self.range.max()),
+            ],
+        );
+        self.broadcast(&message, None);
+    }
+
+    fn send(&mut self, id: usize, message: &str) {
+        let failed = match self.clients.get(&id) {
+            Some(sender) => sender.send(message.to_string()).is_err(),
+            None => false,
+        };
+        // 쓰기 스레드가 끝났다면 이미 연결이 끊긴 것이므로 목록에서 뺀다.
+        if failed {
+            self.clients.remove(&id);
+        }
+    }
+
+    // except에 해당하는 클라이언트를 제외한 모두에게 보낸다.
+    fn broadcast(&mut self, message: &str, except: Option<usize>) {
+        let ids: Vec<usize> = self
+            .clients
+            .keys()
+            .copied()
+            .filter(|id| Some(*id) != except)
+            .collect();
+        for id in ids {
+            self.send(id, message);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::bulls::{self, BullsCowsEngine};
+use crate::cli::{GameMode, Options};
+use crate::difficulty::GuessRange;
+use crate::engine::GameEngine;
+use crate::game::Game;
+use crate::hangman::{self, HangmanEngine};
+use crate::messages::Lang;
+use crate::reverse::Guesser;
+use crate::secret::{self, SecretSource};
+
+// bulls and cows에서 --digits를 주지 않았을 때의 자릿수
+pub const DEFAULT_DIGITS: usize = 4;
+
+// 한 판을 시작하는 데 필요한 설정을 모두 모아둔다.
+// 같은 설정(특히 같은 seed)으로 build하면 항상 같은 게임이 만들어지므로, replay에서도 그대로 사용한다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionConfig {
+    pub seed: u64,
+    pub game: GameMode,
+    // 숫자 맞추기에서만 사용한다.
+    pub range: GuessRange,
+    pub hot_cold: bool,
+    // bulls and cows에서만 사용한다.
+    pub digits: usize,
+    pub max_attempts: u32,
+    pub hints: u32,
+    // 점수표에 남길 난이도 이름
+    pub label: String,
+    pub lang: Lang,
+    // 행맨에서만 사용한다. 다른 게임에서는 빈 문자열이다.
+    pub word: String,
+}
+
+impl SessionConfig {
+    pub fn from_options(options: &Options, seed: u64) -> SessionConfig {
+        let difficulty = options.difficulty;
+        let digits = options.digits.unwrap_or(DEFAULT_DIGITS);
+        let (max_attempts, hints, label) = match options.game {
+            GameMode::Number => (
+                difficulty.max_attempts(),
+                difficulty.hints(),
+                difficulty.to_string(),
+            ),
+            GameMode::BullsCows => (
+                bulls::max_attempts_for(digits),
+                bulls::hints_for(digits),
+                format!("bulls-cows({})", digits),
+            ),
+            // 컴퓨터는 이분 탐색으로 맞추므로 횟수 제한도 hint도 필요 없다.
+            GameMode::Reverse => (0, 0, format!("reverse({})", difficulty)),
+            GameMode::Hangman => (
+                hangman::DEFAULT_LIVES,
+                difficulty.hints(),
+                "hangman".to_string(),
+            ),
+        };
+        let lang = options.lang.unwrap_or_default();
+        SessionConfig {
+            seed,
+            game: options.game,
+            range: difficulty.range(),
+            hot_cold: options.hot_cold,
+            digits,
+            max_attempts,
+            hints: options.hints.unwrap_or(hints),
+            label,
+            lang,
+            word: String::new(),
+        }
+        .with_words(&hangman::bundled_words(lang))
+    }
+
+    // 행맨의 단어를 words 중에서 seed로 고른다. 다른 게임에서는 아무것도 하지 않는다.
+    pub fn with_words(mut self, words: &[String]) -> SessionConfig {
+        if self.game == GameMode::Hangman {
+            self.word = hangman::pick_word(&mut secret::seeded(self.seed), words);
+        }
+        self
+    }
+
+    // seed로 비밀 숫자(코드)를 뽑아서 게임을 만든다.
+    pub fn build(&self) -> Box<dyn Game> {
+        match self.game {
+            GameMode::Number => Box::new(self.build_number()),
+            GameMode::BullsCows => {
+                let mut source = secret::seeded(self.seed);
+                let code = bulls::generate_code(&mut source, self.digits);
+                Box::new(
+                    BullsCowsEngine::new(code)
+                        .with_max_attempts(self.max_attempts)
+                        .with_hints(self.hints)
+                        .with_lang(self.lang),
+                )
+            }
+            GameMode::Reverse => Box::new(Guesser::new(self.range).with_lang(self.lang)),
+            GameMode::Hangman => Box::new(
+                HangmanEngine::new(&self.word)
+                    .with_lives(self.max_attempts)
+                    .with_hints(self.hints)
+                    .with_lang(self.lang),
+            ),
+        }
+    }
+
+    // game 설정과 상관없이 숫자 맞추기 엔진을 만든다. (--json 모드처럼 GameEngine이 직접 필요한 경우)
+    pub fn build_number(&self) -> GameEngine {
+        let mut source = secret::seeded(self.seed);
+        let secret_number = source.next_secret(self.range.min(), self.range.max());
+        let engine = GameEngine::new(secret_number, self.range)
+            .with_max_attempts(self.max_attempts)
+            .with_hints(self.hints)
+            .with_lang(self.lang);
+        if self.hot_cold {
+            engine.with_hot_cold()
+        } else {
+            engine
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+
+use errors::config::{Config, ConfigError};
+
+use crate::difficulty::{Difficulty, GuessRange};
+use crate::messages::Lang;
+
+// 커맨드라인 인자로 매번 주기 번거로운 값들을 설정 파일과 환경 변수로 정해둔다.
+//
+//   # guessing_game.toml
+//   difficulty = "hard"
+//   max = 500
+//   lang = "ko"
+//
+// 같은 값을 GUESSING_GAME_MAX=500 처럼 환경 변수로도 줄 수 있고, 환경 변수가 파일보다 우선한다.
+// 커맨드라인 인자(--max 500)는 Options::parse_with에서 Config 층으로 한 번 더 쌓는다.
+
+// --config가 없으면 현재 디렉터리에서 이 파일을 찾는다. 없으면 기본값을 사용한다.
+pub const DEFAULT_FILE: &str = "guessing_game.toml";
+pub const ENV_PREFIX: &str = "GUESSING_GAME";
+
+// 설정에 쓸 수 있는 키. 오타는 조용히 무시하지 않고 에러로 알린다.
+pub const KEYS: &[&str] = &[
+    "difficulty",
+    "min",
+    "max",
+    "hints",
+    "lang",
+    "name",
+    "scores_file",
+    "host",
+    "port",
+];
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Settings {
+    // min, max가 있으면 Custom 범위가 된다. (--min, --max와 같다)
+    pub difficulty: Difficulty,
+    pub hints: Option<u32>,
+    pub lang: Option<Lang>,
+    pub name: Option<String>,
+    pub scores_file: Option<PathBuf>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+}
+
+impl Settings {
+    pub fn from_config(config: &Config) -> Result<Settings, ConfigError> {
+        config.check_keys(KEYS)?;
+
+        let mut difficulty = config
+            .get_with("difficulty", |name: String| name.parse::<Difficulty>())?
+            .unwrap_or_default();
+        let min = config.get::<u32>("min")?;
+        let max = config.get::<u32>("max")?;
+        if min.is_some() || max.is_some() {
+            let base = difficulty.range();
+            let range = GuessRange::new(min.unwrap_or(base.min()), max.unwrap_or(base.max()))
+                // 범위가 잘못되었으면 주어진 쪽의 위치를 알려준다.
+                .map_err(|e| config.invalid(if max.is_some() { "max" } else { "min" }, e))?;
+            difficulty = Difficulty::Custom(range);
+        }
+
+        Ok(Settings {
+            difficulty,
+            hints: config.get("hints")?,
+            lang: config.get_with("lang", |lang: String| lang.parse::<Lang>())?,
+            name: config.get("name")?,
+            scores_file: config.get("scores_file")?,
+            host: config.get("host")?,
+            port: config.get("port")?,
+        })
+    }
+
+    // 설정 파일과 환경 변수를 읽어서 바로 Settings로 바꾼다.
+    pub fn load<I>(path: Option<&Path>, vars: I) -> Result<Settings, ConfigError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        Settings::from_config(&load_config(path, vars)?)
+    }
+}
+
+// 설정 파일 위에 환경 변수를 덮어써서 읽는다. 값의 확인은 Settings::from_config에서 한다.
+// path가 None이면 DEFAULT_FILE을 읽되, 파일이 없어도 에러가 아니다.
+pub fn load_config<I>(path: Option<&Path>, vars: I) -> Result<Config, ConfigError>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let file = match path {
+        Some(path) => Config::load(path)?,
+        None => Config::load_if_exists(DEFAULT_FILE)?,
+    };
+    Ok(file.merge(Config::from_env(ENV_PREFIX, vars)))
+}
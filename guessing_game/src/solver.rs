@@ -0,0 +1,143 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand::Rng;
+
+use crate::engine::GameEngine;
+
+// 엔진을 상대로 스스로 게임을 하는 봇.
+// 전략은 지금까지의 피드백으로 좁혀진 범위(low..=high)를 받아서 다음 추측을 고른다.
+pub trait Strategy {
+    fn next_guess(&mut self, low: u32, high: u32) -> u32;
+}
+
+// 항상 가운데를 고른다. 가장 적은 횟수로 맞출 수 있다.
+pub struct BinarySearch;
+
+impl Strategy for BinarySearch {
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        low + (high - low) / 2
+    }
+}
+
+// 가장 작은 숫자부터 하나씩 올라간다.
+pub struct Linear;
+
+impl Strategy for Linear {
+    fn next_guess(&mut self, low: u32, _high: u32) -> u32 {
+        low
+    }
+}
+
+// 남은 범위 안에서 아무거나 고른다.
+pub struct RandomGuess<R: Rng> {
+    rng: R,
+}
+
+impl<R: Rng> RandomGuess<R> {
+    pub fn new(rng: R) -> RandomGuess<R> {
+        RandomGuess { rng }
+    }
+}
+
+impl<R: Rng> Strategy for RandomGuess<R> {
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        self.rng.gen_range(u64::from(low), u64::from(high) + 1) as u32
+    }
+}
+
+// 가운데 대신 ratio 지점을 고른다. ratio가 0.5면 BinarySearch와 같다.
+pub struct BiasedMidpoint {
+    ratio: f64,
+}
+
+impl BiasedMidpoint {
+    pub fn new(ratio: f64) -> BiasedMidpoint {
+        if !(0.0..=1.0).contains(&ratio) {
+            panic!("ratio must be between 0 and 1, got {}.", ratio);
+        }
+        BiasedMidpoint { ratio }
+    }
+}
+
+impl Strategy for BiasedMidpoint {
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        low + (f64::from(high - low) * self.ratio) as u32
+    }
+}
+
+// 커맨드라인에서 고를 수 있는 전략 이름들
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum StrategyKind {
+    #[default]
+    Binary,
+    Random,
+    Linear,
+    Biased,
+}
+
+impl StrategyKind {
+    // Random 전략은 seed가 주어지면 매번 같은 순서로 추측한다.
+    pub fn build(&self, seed: Option<u64>) -> Box<dyn Strategy> {
+        match self {
+            StrategyKind::Binary => Box::new(BinarySearch),
+            StrategyKind::Linear => Box::new(Linear),
+            StrategyKind::Biased => Box::new(BiasedMidpoint::new(1.0 / 3.0)),
+            StrategyKind::Random => match seed {
+                Some(seed) => Box::new(RandomGuess::new(crate::secret::seeded(seed))),
+                None => Box::new(RandomGuess::new(rand::thread_rng())),
+            },
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            StrategyKind::Binary => "binary",
+            StrategyKind::Random => "random",
+            StrategyKind::Linear => "linear",
+            StrategyKind::Biased => "biased",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<StrategyKind, String> {
+        match s {
+            "binary" => Ok(StrategyKind::Binary),
+            "random" => Ok(StrategyKind::Random),
+            "linear" => Ok(StrategyKind::Linear),
+            "biased" => Ok(StrategyKind::Biased),
+            other => Err(format!(
+                "unknown strategy: {} (expected binary, random, linear or biased)",
+                other
+            )),
+        }
+    }
+}
+
+// 봇이 한 판을 한 결과
+#[derive(Debug, PartialEq)]
+pub struct Solved {
+    pub attempts: u32,
+    pub won: bool,
+}
+
+// 게임이 끝날 때까지(맞추거나 시도 횟수를 다 쓸 때까지) 전략대로 추측한다.
+pub fn solve(engine: &mut GameEngine, strategy: &mut dyn Strategy) -> Solved {
+    while !engine.is_over() {
+        let plausible = engine.plausible_range();
+        let guess = strategy.next_guess(*plausible.start(), *plausible.end());
+        engine
+            .guess(guess)
+            .expect("strategy guessed outside of the plausible range");
+    }
+    Solved {
+        attempts: engine.attempts(),
+        won: engine.is_won(),
+    }
+}
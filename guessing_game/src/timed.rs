@@ -0,0 +1,188 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::clock::Clock;
+use crate::game::{Game, Outcome};
+use crate::messages::Messages;
+use crate::score::Performance;
+
+// 추측 하나가 받아들여진 시점의 기록
+#[derive(Debug, Clone, PartialEq)]
+pub struct Split {
+    pub guess: String,
+    // 직전 추측(첫 추측이면 게임 시작)부터 걸린 시간
+    pub split: Duration,
+    // 게임 시작부터 걸린 시간
+    pub total: Duration,
+}
+
+// 아무 게임이나 감싸서 시간을 재는 Game.
+// - 제한 시간(countdown): 추측마다 제한 시간을 넘기면 그 추측은 무시되고 기회만 하나 줄어든다.
+// - speedrun: 추측마다 걸린 시간(split)을 기록해뒀다가 게임이 끝나면 보여준다.
+//
+// 입력을 받은 다음에 시간을 확인하므로, 입력을 기다리는 도중에 끊지는 않는다.
+pub struct Timed {
+    game: Box<dyn Game>,
+    clock: Box<dyn Clock>,
+    limit: Option<Duration>,
+    speedrun: bool,
+    started: Duration,
+    // 이번 차례가 시작된 시간. 추측이 받아들여지거나 시간이 초과되면 새 차례가 시작된다.
+    turn_started: Duration,
+    splits: Vec<Split>,
+}
+
+impl Timed {
+    pub fn new(game: Box<dyn Game>, clock: Box<dyn Clock>) -> Timed {
+        let now = clock.now();
+        Timed {
+            game,
+            clock,
+            limit: None,
+            speedrun: false,
+            started: now,
+            turn_started: now,
+            splits: Vec::new(),
+        }
+    }
+
+    pub fn with_time_limit(mut self, limit: Duration) -> Timed {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_speedrun(mut self) -> Timed {
+        self.speedrun = true;
+        self
+    }
+
+    pub fn splits(&self) -> &[Split] {
+        &self.splits
+    }
+
+    // 게임을 시작한 뒤로 지난 시간
+    pub fn elapsed(&self) -> Duration {
+        self.clock.now() - self.started
+    }
+
+    fn write_splits(&self, output: &mut dyn Write) -> io::Result<()> {
+        let messages = self.messages();
+        for (i, split) in self.splits.iter().enumerate() {
+            writeln!(
+                output,
+                "{}",
+                messages.format(
+                    "speedrun.split",
+                    &[
+                        ("number", &(i + 1)),
+                        ("guess", &split.guess),
+                        ("split", &seconds(split.split)),
+                        ("total", &seconds(split.total)),
+                    ]
+                )
+            )?;
+        }
+        writeln!(
+            output,
+            "{}",
+            messages.format("speedrun.total", &[("total", &seconds(self.elapsed()))])
+        )
+    }
+}
+
+// 1.5초 -> "1.50"
+fn seconds(duration: Duration) -> String {
+    format!("{:.2}", duration.as_secs_f64())
+}
+
+impl Game for Timed {
+    fn write_intro(&self, output: &mut dyn Write) -> io::Result<()> {
+        self.game.write_intro(output)?;
+        let messages = self.messages();
+        if let Some(limit) = self.limit {
+            writeln!(
+                output,
+                "{}",
+                messages.format("timed.intro", &[("seconds", &limit.as_secs_f64())])
+            )?;
+        }
+        if self.speedrun {
+            writeln!(output, "{}", messages.get("speedrun.intro"))?;
+        }
+        Ok(())
+    }
+
+    fn submit(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        let now = self.clock.now();
+        let taken = now - self.turn_started;
+        if let Some(limit) = self.limit.filter(|limit| taken > *limit) {
+            writeln!(
+                output,
+                "{}",
+                self.messages().format(
+                    "timed.too_slow",
+                    &[("taken", &seconds(taken)), ("limit", &seconds(limit))]
+                )
+            )?;
+            self.turn_started = now;
+            let outcome = self.game.skip_turn(output)?;
+            if outcome.is_some() && self.speedrun {
+                self.write_splits(output)?;
+            }
+            return Ok(outcome);
+        }
+
+        let before = self.game.attempts();
+        let outcome = self.game.submit(line, output)?;
+        if self.game.attempts() > before {
+            let previous = self
+                .splits
+                .last()
+                .map_or(Duration::ZERO, |split| split.total);
+            let total = now - self.started;
+            self.splits.push(Split {
+                guess: self.game.last_guess().unwrap_or_default(),
+                split: total - previous,
+                total,
+            });
+            self.turn_started = now;
+        }
+
+        if outcome.is_some() && self.speedrun {
+            self.write_splits(output)?;
+        }
+        Ok(outcome)
+    }
+
+    fn write_hint(&mut self, output: &mut dyn Write) -> io::Result<()> {
+        self.game.write_hint(output)
+    }
+
+    fn write_history(&self, output: &mut dyn Write) -> io::Result<()> {
+        self.game.write_history(output)
+    }
+
+    fn skip_turn(&mut self, output: &mut dyn Write) -> io::Result<Option<Outcome>> {
+        self.game.skip_turn(output)
+    }
+
+    fn prompt_key(&self) -> &'static str {
+        self.game.prompt_key()
+    }
+
+    fn attempts(&self) -> u32 {
+        self.game.attempts()
+    }
+
+    fn last_guess(&self) -> Option<String> {
+        self.game.last_guess()
+    }
+
+    fn performance(&self, elapsed: Duration) -> Performance {
+        self.game.performance(elapsed)
+    }
+
+    fn messages(&self) -> Messages {
+        self.game.messages()
+    }
+}
@@ -0,0 +1,286 @@
+use std::cmp::Ordering;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::clock::Clock;
+use crate::difficulty::GuessRange;
+use crate::engine::GameEngine;
+use crate::game::{step, Game, Outcome};
+use crate::input::{parse_input, Input};
+use crate::score::{score, Performance};
+
+// --tui: 한 줄씩 주고받는 대신 터미널 전체를 쓰는 화면.
+// 게임 진행은 줄 단위 모드와 똑같이 step()에 맡기고, 여기서는 키 입력을 모아서 한 줄을 만들고
+// 엔진의 상태(기록, 가능한 범위, 남은 기회, 점수)를 그려주기만 한다.
+// 터미널 없이 테스트할 수 있도록 키 처리와 화면 그리기(Tui)와 실제 터미널(run)을 나눠둔다.
+
+// 가능한 범위를 보여주는 막대의 최대 칸 수
+const BAR_WIDTH: usize = 50;
+// 화면에 보여줄 최근 추측의 수
+const HISTORY_ROWS: usize = 8;
+
+// 필요한 키만 뽑아둔 것. crossterm의 KeyEvent는 run()에서 이것으로 바꾼다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Key {
+    Char(char),
+    Backspace,
+    Enter,
+    Esc,
+}
+
+pub struct Tui {
+    engine: GameEngine,
+    clock: Box<dyn Clock>,
+    // 입력 중인 줄
+    input: String,
+    // 마지막으로 Enter를 눌렀을 때 엔진이 돌려준 문장들
+    feedback: Vec<String>,
+    finished: bool,
+}
+
+impl Tui {
+    pub fn new(engine: GameEngine, clock: Box<dyn Clock>) -> Tui {
+        let mut intro = Vec::new();
+        engine
+            .write_intro(&mut intro)
+            .expect("writing to a Vec never fails");
+        Tui {
+            engine,
+            clock,
+            input: String::new(),
+            feedback: lines(&intro),
+            finished: false,
+        }
+    }
+
+    pub fn engine(&self) -> &GameEngine {
+        &self.engine
+    }
+
+    pub fn into_engine(self) -> GameEngine {
+        self.engine
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn feedback(&self) -> &[String] {
+        &self.feedback
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    // 키 하나를 처리한다. 이 키로 게임이 끝났으면 결과를 돌려준다.
+    pub fn press(&mut self, key: Key) -> io::Result<Option<Outcome>> {
+        if self.finished {
+            return Ok(None);
+        }
+        let line = match key {
+            Key::Char(c) if !c.is_control() => {
+                self.input.push(c);
+                return Ok(None);
+            }
+            Key::Char(_) => return Ok(None),
+            Key::Backspace => {
+                self.input.pop();
+                return Ok(None);
+            }
+            // Esc는 quit을 입력한 것과 같다.
+            Key::Esc => "quit".to_string(),
+            // String::new()로 바꿔 넣고 원래 값을 꺼내온다.
+            Key::Enter => std::mem::take(&mut self.input),
+        };
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let mut output = Vec::new();
+        let outcome = step(&mut self.engine, &line, &mut output)?;
+        self.feedback = lines(&output);
+        self.finished = outcome.is_some();
+        Ok(outcome)
+    }
+
+    // 입력하는 동안 보여주는 한 줄. 아직 Enter를 누르지 않았어도 잘못된 입력이나
+    // 이미 답이 될 수 없는 숫자를 바로 알려준다.
+    pub fn preview(&self) -> Option<String> {
+        if self.input.trim().is_empty() {
+            return None;
+        }
+        let messages = self.engine.messages();
+        match parse_input(&self.input, self.engine.range()) {
+            Ok(Input::Guess(guess)) if !self.engine.plausible_range().contains(&guess) => {
+                Some(messages.format("tui.ruled_out", &[("guess", &guess)]))
+            }
+            Ok(_) => None,
+            Err(e) => Some(e.message(&messages)),
+        }
+    }
+
+    // 지금 맞히면 받을 점수. 게임이 끝났으면 실제 점수다.
+    pub fn potential_score(&self) -> u32 {
+        let mut performance = Performance::from_engine(&self.engine, self.clock.now());
+        if !self.engine.is_over() {
+            performance.attempts += 1;
+            if self.engine.max_attempts().is_none() {
+                performance.max_attempts = performance.attempts;
+            }
+        }
+        score(&performance)
+    }
+
+    // 화면에 그릴 줄들. 한 줄은 width 글자를 넘지 않는다.
+    pub fn render(&self, width: usize) -> Vec<String> {
+        let messages = self.engine.messages();
+        let range = self.engine.range();
+        let plausible = self.engine.plausible_range();
+        let mut rows = vec![messages.get("number.intro").to_string(), String::new()];
+
+        rows.push(interval_bar(range, &plausible, width.saturating_sub(2)));
+        rows.push(messages.format(
+            "tui.plausible",
+            &[("min", plausible.start()), ("max", plausible.end())],
+        ));
+        rows.push(match self.engine.attempts_left() {
+            Some(left) => messages.format("attempts_left", &[("left", &left)]),
+            None => messages.format("tui.attempts", &[("attempts", &self.engine.attempts())]),
+        });
+        rows.push(messages.format("tui.hints", &[("hints", &self.engine.hints_left())]));
+        rows.push(messages.format("tui.score", &[("score", &self.potential_score())]));
+        rows.push(messages.format("tui.time", &[("secs", &self.clock.now().as_secs())]));
+        rows.push(String::new());
+
+        rows.push(messages.get("tui.history").to_string());
+        let history = self.engine.history();
+        if history.is_empty() {
+            rows.push(format!("  {}", messages.get("no_guesses")));
+        }
+        for (guess, ordering) in history
+            .iter()
+            .skip(history.len().saturating_sub(HISTORY_ROWS))
+        {
+            let key = match ordering {
+                Ordering::Less => "number.result.too_small",
+                Ordering::Greater => "number.result.too_big",
+                Ordering::Equal => "number.result.correct",
+            };
+            rows.push(format!("  {:>6}  {}", guess, messages.get(key)));
+        }
+        rows.push(String::new());
+
+        rows.extend(self.feedback.iter().cloned());
+        if self.finished {
+            rows.push(messages.get("tui.press_key").to_string());
+        } else {
+            rows.push(format!("> {}", self.input));
+            rows.push(self.preview().unwrap_or_default());
+            rows.push(messages.get("tui.help").to_string());
+        }
+
+        rows.into_iter()
+            .map(|row| row.chars().take(width).collect())
+            .collect()
+    }
+}
+
+// range 전체를 칸으로 나눠서, plausible 안에 들어가는 칸은 '#', 아닌 칸은 '.'으로 그린다.
+// 칸 수는 width와 BAR_WIDTH, 범위의 크기 중 가장 작은 것이다.
+pub fn interval_bar(range: GuessRange, plausible: &RangeInclusive<u32>, width: usize) -> String {
+    let cells = (width.min(BAR_WIDTH) as u64).min(range.size());
+    let min = u64::from(range.min());
+    let (low, high) = (u64::from(*plausible.start()), u64::from(*plausible.end()));
+    let bar: String = (0..cells)
+        .map(|cell| {
+            // 이 칸이 맡는 숫자들: first..=last
+            let first = min + range.size() * cell / cells;
+            let last = min + range.size() * (cell + 1) / cells - 1;
+            if low <= high && first <= high && low <= last {
+                '#'
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    format!("[{}]", bar)
+}
+
+fn lines(output: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(output)
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+// 실제 터미널에서 게임을 한다. 끝나면 터미널을 원래대로 돌려놓는다.
+pub fn run(tui: &mut Tui) -> io::Result<Outcome> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    let result = event_loop(tui, &mut stdout);
+    // 게임 중에 에러가 났어도 터미널은 돌려놓아야 한다.
+    let restored = execute!(stdout, Show, LeaveAlternateScreen).and(terminal::disable_raw_mode());
+    let outcome = result?;
+    restored?;
+    Ok(outcome)
+}
+
+fn event_loop(tui: &mut Tui, output: &mut impl Write) -> io::Result<Outcome> {
+    let mut outcome = None;
+    loop {
+        draw(tui, output)?;
+        // 걸린 시간과 점수가 바뀌는 것을 보여주려고, 키를 누르지 않아도 1초마다 다시 그린다.
+        if !event::poll(Duration::from_secs(1))? {
+            continue;
+        }
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        // 게임이 끝난 화면에서는 아무 키나 누르면 나간다.
+        if let Some(outcome) = outcome {
+            return Ok(outcome);
+        }
+        if let Some(key) = to_key(key) {
+            outcome = tui.press(key)?;
+        }
+    }
+}
+
+fn to_key(key: KeyEvent) -> Option<Key> {
+    match key.code {
+        // raw mode에서는 Ctrl-C가 시그널이 되지 않으므로 직접 처리한다.
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Key::Esc),
+        KeyCode::Char(c) => Some(Key::Char(c)),
+        KeyCode::Backspace => Some(Key::Backspace),
+        KeyCode::Enter => Some(Key::Enter),
+        KeyCode::Esc => Some(Key::Esc),
+        _ => None,
+    }
+}
+
+fn draw(tui: &Tui, output: &mut impl Write) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let rows = tui.render(usize::from(width));
+    // 화면보다 길면 위쪽을 잘라내서 입력하는 줄이 항상 보이게 한다.
+    let skip = rows.len().saturating_sub(usize::from(height));
+    for (y, row) in rows.iter().skip(skip).enumerate() {
+        queue!(
+            output,
+            MoveTo(0, y as u16),
+            Print(row),
+            Clear(ClearType::UntilNewLine)
+        )?;
+    }
+    queue!(output, Clear(ClearType::FromCursorDown))?;
+    output.flush()
+}
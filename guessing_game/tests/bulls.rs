@@ -0,0 +1,109 @@
+use guessing_game::bulls::{format_code, generate_code, BullsCowsEngine, Score};
+use guessing_game::cli::{GameMode, Options};
+use guessing_game::game::step;
+use guessing_game::input::{parse_code, Input, InputError};
+use guessing_game::secret::{self, FixedSequence};
+use guessing_game::{play_game, Outcome};
+
+#[test]
+fn counts_bulls_and_cows() {
+    let mut engine = BullsCowsEngine::new(vec![1, 2, 3, 4]);
+    assert_eq!(engine.guess(&[5, 6, 7, 8]), Ok(Score { bulls: 0, cows: 0 }));
+    assert_eq!(engine.guess(&[1, 3, 2, 9]), Ok(Score { bulls: 1, cows: 2 }));
+    assert_eq!(engine.guess(&[4, 3, 2, 1]), Ok(Score { bulls: 0, cows: 4 }));
+    assert!(!engine.is_won());
+    assert_eq!(engine.guess(&[1, 2, 3, 4]), Ok(Score { bulls: 4, cows: 0 }));
+    assert!(engine.is_won());
+    assert_eq!(engine.attempts(), 4);
+}
+
+#[test]
+fn generated_codes_have_unique_digits_and_follow_the_seed() {
+    let code = generate_code(&mut secret::seeded(9), 10);
+    let mut sorted = code.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..10).collect::<Vec<u8>>());
+
+    assert_eq!(
+        generate_code(&mut secret::seeded(3), 4),
+        generate_code(&mut secret::seeded(3), 4)
+    );
+    // 남은 숫자 목록의 인덱스를 고르므로 같은 값이 반복되어도 코드는 겹치지 않는다.
+    let mut source = FixedSequence::new(vec![0]);
+    assert_eq!(format_code(&generate_code(&mut source, 4)), "0123");
+}
+
+#[test]
+fn validates_codes() {
+    assert_eq!(parse_code("0123\n", 4), Ok(Input::Guess(vec![0, 1, 2, 3])));
+    assert_eq!(parse_code("\n", 4), Err(InputError::Empty));
+    assert_eq!(
+        parse_code("12a4\n", 4),
+        Err(InputError::NotANumber("12a4".to_string()))
+    );
+    assert_eq!(
+        parse_code("123\n", 4),
+        Err(InputError::WrongLength {
+            expected: 4,
+            got: 3
+        })
+    );
+    assert_eq!(parse_code("1231\n", 4), Err(InputError::RepeatedDigit('1')));
+}
+
+#[test]
+fn scripted_session_shares_the_input_loop() {
+    let mut engine = BullsCowsEngine::new(vec![1, 2, 3, 4])
+        .with_max_attempts(3)
+        .with_hints(1);
+    let mut output = Vec::new();
+    let script = "hint\n5678\n1243\nhistory\n1234\n";
+    let outcome = play_game(&mut engine, script.as_bytes(), &mut output).unwrap();
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Hint: digit 1 is 1. (0 hints left)\n"));
+    assert!(output.contains("You guessed: 5678\n0 bulls, 0 cows\nAttempts left: 2\n"));
+    assert!(output.contains("  2. 1243 (2 bulls, 2 cows)\n"));
+    assert!(output.ends_with("You win!\n"));
+}
+
+#[test]
+fn input_after_the_game_is_over_is_refused() {
+    let mut engine = BullsCowsEngine::new(vec![1, 2, 3]).with_max_attempts(1);
+    let mut output = Vec::new();
+    assert!(step(&mut engine, "456\n", &mut output).unwrap().is_some());
+
+    let mut output = Vec::new();
+    assert_eq!(step(&mut engine, "123\n", &mut output).unwrap(), None);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "You guessed: 123\nThe game is already over.\n"
+    );
+}
+
+#[test]
+fn running_out_of_attempts_reveals_the_code() {
+    let mut engine = BullsCowsEngine::new(vec![9, 8, 7]).with_max_attempts(1);
+    let mut output = Vec::new();
+    let outcome = play_game(&mut engine, &b"123\n"[..], &mut output).unwrap();
+    assert_eq!(
+        outcome,
+        Outcome::Lost {
+            attempts: 1,
+            secret: "987".to_string()
+        }
+    );
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .ends_with("You lose! The code was 987.\n"));
+}
+
+#[test]
+fn mode_flag_selects_bulls_and_cows() {
+    let args = ["--mode", "bulls-cows", "--digits", "5"];
+    let options = Options::parse(args.iter().map(|arg| arg.to_string())).unwrap();
+    assert_eq!(options.game, GameMode::BullsCows);
+    assert_eq!(options.digits, Some(5));
+    assert!(Options::parse(vec!["--digits".to_string(), "11".to_string()]).is_err());
+}
@@ -0,0 +1,42 @@
+use guessing_game::cli::{CliError, Options};
+use guessing_game::{Difficulty, GuessRange};
+
+fn parse(args: &[&str]) -> Result<Options, CliError> {
+    Options::parse(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn presets_have_expected_ranges() {
+    assert_eq!(Difficulty::Easy.range(), GuessRange::new(1, 10).unwrap());
+    assert_eq!(Difficulty::Normal.range(), GuessRange::new(1, 100).unwrap());
+    assert_eq!(
+        Difficulty::Hard.range(),
+        GuessRange::new(1, 10_000).unwrap()
+    );
+}
+
+#[test]
+fn difficulty_flags_select_presets() {
+    assert_eq!(parse(&[]).unwrap().difficulty, Difficulty::Normal);
+    assert_eq!(parse(&["--easy"]).unwrap().difficulty, Difficulty::Easy);
+    assert_eq!(
+        parse(&["--difficulty", "hard"]).unwrap().difficulty,
+        Difficulty::Hard
+    );
+    assert!(parse(&["--difficulty", "insane"]).is_err());
+}
+
+#[test]
+fn min_and_max_make_a_custom_range() {
+    let options = parse(&["--min", "50", "--max", "60"]).unwrap();
+    assert_eq!(
+        options.difficulty,
+        Difficulty::Custom(GuessRange::new(50, 60).unwrap())
+    );
+
+    // 주어지지 않은 쪽은 선택된 난이도의 값을 사용한다.
+    let options = parse(&["--easy", "--max", "20"]).unwrap();
+    assert_eq!(options.difficulty.range(), GuessRange::new(1, 20).unwrap());
+
+    assert!(parse(&["--min", "10", "--max", "10"]).is_err());
+}
@@ -0,0 +1,210 @@
+use std::env;
+use std::fs;
+
+use guessing_game::cli::{CliError, GameMode, Options};
+use guessing_game::game::{play_with, step};
+use guessing_game::hangman::{self, HangmanEngine, HangmanError, HangmanGuess, LetterResult};
+use guessing_game::hint::HintError;
+use guessing_game::input::{parse_hangman, Input, InputError};
+use guessing_game::messages::Lang;
+use guessing_game::replay::{replay, Recorder, SessionLog};
+use guessing_game::session::SessionConfig;
+use guessing_game::{play_game, Outcome};
+
+fn options(args: &[&str]) -> Result<Options, CliError> {
+    Options::parse(args.iter().map(|arg| arg.to_string()))
+}
+
+fn run(engine: &mut HangmanEngine, script: &str) -> (Outcome, String) {
+    let mut output = Vec::new();
+    let outcome = play_game(engine, script.as_bytes(), &mut output).unwrap();
+    (outcome, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn input_is_split_into_letters_and_words() {
+    assert_eq!(
+        parse_hangman("R\n"),
+        Ok(Input::Guess(HangmanGuess::Letter('r')))
+    );
+    assert_eq!(
+        parse_hangman(" 과 "),
+        Ok(Input::Guess(HangmanGuess::Letter('과')))
+    );
+    assert_eq!(
+        parse_hangman("소유권"),
+        Ok(Input::Guess(HangmanGuess::Word("소유권".to_string())))
+    );
+    assert!(matches!(parse_hangman("hint"), Ok(Input::Command(_))));
+    assert_eq!(
+        parse_hangman("7"),
+        Err(InputError::NotALetter("7".to_string()))
+    );
+}
+
+#[test]
+fn hangul_words_are_guessed_by_characters_not_bytes() {
+    // "사과"는 6바이트지만 글자는 2개다.
+    let mut engine = HangmanEngine::new("사과");
+    assert_eq!(engine.length(), 2);
+    assert_eq!(engine.masked(), "_ _");
+
+    assert_eq!(engine.guess_letter('과'), Ok(LetterResult::Hit(1)));
+    assert_eq!(engine.masked(), "_ 과");
+    assert_eq!(engine.guess_letter('배'), Ok(LetterResult::Miss));
+    assert_eq!(engine.lives(), hangman::DEFAULT_LIVES - 1);
+    assert!(engine.used_letters().contains(&'과'));
+    assert!(engine.used_letters().contains(&'배'));
+
+    assert_eq!(engine.guess_letter('사'), Ok(LetterResult::Hit(1)));
+    assert!(engine.is_won());
+    assert_eq!(engine.reveal(), Some("사과".to_string()));
+}
+
+#[test]
+fn repeated_letters_do_not_cost_lives() {
+    let mut engine = HangmanEngine::new("Borrow").with_lives(3);
+    assert_eq!(engine.guess_letter('r'), Ok(LetterResult::Hit(2)));
+    assert_eq!(
+        engine.guess_letter('R'),
+        Err(HangmanError::AlreadyUsed('r'))
+    );
+    assert_eq!(engine.guess_letter('z'), Ok(LetterResult::Miss));
+    assert_eq!(
+        engine.guess_letter('z'),
+        Err(HangmanError::AlreadyUsed('z'))
+    );
+    assert_eq!(engine.lives(), 2);
+    assert_eq!(engine.attempts(), 2);
+    assert_eq!(engine.masked(), "_ _ r r _ _");
+}
+
+#[test]
+fn wrong_words_cost_a_life_and_right_words_win() {
+    let mut engine = HangmanEngine::new("trait");
+    assert_eq!(engine.guess_word("crate"), Ok(false));
+    assert_eq!(engine.lives(), hangman::DEFAULT_LIVES - 1);
+    assert_eq!(engine.guess_word("TRAIT"), Ok(true));
+    assert!(engine.is_won());
+    assert_eq!(engine.guess_letter('x'), Err(HangmanError::GameOver));
+}
+
+#[test]
+fn scripted_game_is_won() {
+    let mut engine = HangmanEngine::new("소유권");
+    let (outcome, output) = run(&mut engine, "유\n유\n7\n소\n권\n");
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+    assert!(output.contains("Word: _ 유 _\n"));
+    assert!(output.contains("You already tried '유'.\n"));
+    assert!(output.contains("Word: 소 유 권\nYou win!\n"));
+}
+
+#[test]
+fn running_out_of_lives_reveals_the_word() {
+    let mut engine = HangmanEngine::new("crate")
+        .with_lives(2)
+        .with_lang(Lang::Ko);
+    let (outcome, output) = run(&mut engine, "x\nstruct\n");
+    assert_eq!(
+        outcome,
+        Outcome::Lost {
+            attempts: 2,
+            secret: "crate".to_string()
+        }
+    );
+    assert!(output.contains("졌습니다! 정답은 crate입니다."));
+}
+
+#[test]
+fn input_after_the_game_is_over_is_refused() {
+    let mut engine = HangmanEngine::new("rust");
+    let mut output = Vec::new();
+    assert!(step(&mut engine, "rust\n", &mut output).unwrap().is_some());
+
+    for line in ["r\n", "crab\n"] {
+        let mut output = Vec::new();
+        assert_eq!(step(&mut engine, line, &mut output).unwrap(), None);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "The game is already over.\n"
+        );
+    }
+}
+
+#[test]
+fn hints_never_finish_the_word() {
+    let mut engine = HangmanEngine::new("borrow").with_hints(5);
+    assert_eq!(engine.use_hint(), Ok('b'));
+    assert_eq!(engine.use_hint(), Ok('o'));
+    // 남은 글자가 r, w 두 종류
+    assert_eq!(engine.use_hint(), Ok('r'));
+    assert_eq!(engine.use_hint(), Err(HintError::WouldSolve));
+    assert_eq!(engine.hints_used(), 3);
+    assert_eq!(engine.hints_left(), 2);
+    assert_eq!(engine.lives(), hangman::DEFAULT_LIVES);
+
+    // 화면에서는 힌트가 다 떨어진 것과 구분해서 알려준다.
+    let mut engine = HangmanEngine::new("aab").with_hints(1);
+    let (_, output) = run(&mut engine, "b\nhint\n");
+    assert!(output.contains("A hint would give away the whole word."));
+    assert!(!output.contains("No hints left."));
+
+    let mut engine = HangmanEngine::new("abc").with_hints(0);
+    assert_eq!(engine.use_hint(), Err(HintError::NoHintsLeft));
+}
+
+#[test]
+fn word_lists_are_loaded_from_files() {
+    let path = env::temp_dir().join(format!("hangman-words-{}.txt", std::process::id()));
+    fs::write(&path, "# 러스트 용어\nborrow\n\n  소유권  \n").unwrap();
+    assert_eq!(
+        hangman::load_words(&path).unwrap(),
+        vec!["borrow".to_string(), "소유권".to_string()]
+    );
+
+    fs::write(&path, "# nothing here\n").unwrap();
+    assert!(hangman::load_words(&path).is_err());
+    fs::write(&path, "borrow\n1234\n").unwrap();
+    assert!(hangman::load_words(&path).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn hangman_mode_is_selected_from_the_command_line() {
+    let parsed = options(&["--mode", "hangman", "--words", "words.txt"]).unwrap();
+    assert_eq!(parsed.game, GameMode::Hangman);
+    assert_eq!(parsed.words, Some("words.txt".into()));
+    assert!(options(&["--words", "words.txt"]).is_err());
+
+    let parsed = options(&["--mode", "hangman", "--lang", "ko"]).unwrap();
+    let config = SessionConfig::from_options(&parsed, 3);
+    assert!(hangman::bundled_words(Lang::Ko).contains(&config.word));
+    // 같은 seed면 같은 단어를 고른다.
+    assert_eq!(config, SessionConfig::from_options(&parsed, 3));
+
+    let words = vec!["벡터".to_string()];
+    assert_eq!(config.with_words(&words).word, "벡터");
+}
+
+#[test]
+fn hangman_sessions_replay() {
+    let options = options(&["--mode", "hangman"]).unwrap();
+    let config = SessionConfig::from_options(&options, 5).with_words(&["closure".to_string()]);
+
+    let mut log = Vec::new();
+    let mut recorder = Recorder::new(&mut log, &config).unwrap();
+    let mut game = config.build();
+    let outcome = play_with(
+        &mut *game,
+        "e\nz\nclosure\n".as_bytes(),
+        Vec::new(),
+        |game, line, out| recorder.record_step(game, line, out),
+    )
+    .unwrap();
+    recorder.record_outcome(&outcome).unwrap();
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+
+    let log = SessionLog::parse(&String::from_utf8(log).unwrap()).unwrap();
+    assert_eq!(log.config.word, "closure");
+    assert!(replay(&log).unwrap().is_ok());
+}
@@ -0,0 +1,57 @@
+use guessing_game::hint::{HintError, Proximity, Trend};
+use guessing_game::{play, Difficulty, GameEngine, GuessRange};
+
+#[test]
+fn proximity_buckets_scale_with_range() {
+    let range = Difficulty::Normal.range();
+    assert_eq!(Proximity::between(50, 50, range), Proximity::Burning);
+    assert_eq!(Proximity::between(48, 50, range), Proximity::Burning);
+    assert_eq!(Proximity::between(45, 50, range), Proximity::Hot);
+    assert_eq!(Proximity::between(40, 50, range), Proximity::Warm);
+    assert_eq!(Proximity::between(20, 50, range), Proximity::Cold);
+    assert_eq!(Proximity::between(1, 50, range), Proximity::Freezing);
+
+    // 작은 범위에서도 바로 옆 숫자는 Burning이다.
+    let easy = Difficulty::Easy.range();
+    assert_eq!(Proximity::between(4, 5, easy), Proximity::Burning);
+    assert_eq!(Proximity::between(1, 10, easy), Proximity::Freezing);
+}
+
+#[test]
+fn trend_compares_with_previous_guess() {
+    let mut engine = GameEngine::new(50, Difficulty::Normal.range());
+    engine.guess(10).unwrap();
+    assert_eq!(engine.trend(), None);
+    engine.guess(40).unwrap();
+    assert_eq!(engine.trend(), Some(Trend::Warmer));
+    engine.guess(90).unwrap();
+    assert_eq!(engine.trend(), Some(Trend::Colder));
+    engine.guess(10).unwrap();
+    assert_eq!(engine.trend(), Some(Trend::Same));
+}
+
+#[test]
+fn hints_halve_the_plausible_interval() {
+    let range = GuessRange::new(1, 100).unwrap();
+    let mut engine = GameEngine::new(70, range).with_hints(2);
+    assert_eq!(engine.use_hint(), Ok(51..=100));
+    engine.guess(60).unwrap();
+    assert_eq!(engine.use_hint(), Ok(61..=80));
+    assert_eq!(engine.use_hint(), Err(HintError::NoHintsLeft));
+    assert_eq!(engine.hints_used(), 2);
+    assert_eq!(engine.plausible_range(), 61..=80);
+
+    engine.guess(70).unwrap();
+    assert_eq!(engine.use_hint(), Err(HintError::GameOver));
+}
+
+#[test]
+fn hot_cold_mode_reports_proximity() {
+    let mut engine = GameEngine::new(50, Difficulty::Normal.range()).with_hot_cold();
+    let mut output = Vec::new();
+    play(&mut engine, &b"10\n45\n50\n"[..], &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Too small!\nFreezing!\n"));
+    assert!(output.contains("Too small!\nHot! (warmer than your last guess)\n"));
+}
@@ -0,0 +1,109 @@
+use guessing_game::cli::Options;
+use guessing_game::difficulty::GuessRange;
+use guessing_game::hotseat::{play_match, RoundResult};
+use guessing_game::{Game, GameEngine};
+
+fn players() -> Vec<String> {
+    vec!["alice".to_string(), "bob".to_string()]
+}
+
+// 라운드마다 비밀 숫자가 secrets[round - 1]인 게임을 만든다.
+fn new_game(secrets: &'static [u32]) -> impl FnMut(u32) -> Box<dyn Game> {
+    move |round| {
+        let range = GuessRange::new(1, 100).unwrap();
+        Box::new(GameEngine::new(secrets[round as usize - 1], range).with_max_attempts(4))
+    }
+}
+
+fn run(
+    rounds: u32,
+    secrets: &'static [u32],
+    script: &str,
+) -> (guessing_game::hotseat::MatchReport, String) {
+    let mut output = Vec::new();
+    let report = play_match(
+        &players(),
+        rounds,
+        new_game(secrets),
+        script.as_bytes(),
+        &mut output,
+    )
+    .unwrap();
+    (report, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn players_alternate_and_first_turn_rotates() {
+    // 1라운드: alice 50, bob 42(정답)
+    // 2라운드: bob부터. bob 10, alice는 잘못 입력한 뒤 70(정답)
+    let script = "50\n42\n10\nabc\n70\n";
+    let (report, text) = run(2, &[42, 70], script);
+
+    assert!(report.finished);
+    assert_eq!(
+        report.rounds,
+        vec![
+            RoundResult {
+                winner: Some(1),
+                attempts: vec![1, 1],
+            },
+            RoundResult {
+                winner: Some(0),
+                attempts: vec![1, 1],
+            },
+        ]
+    );
+    assert!(text.contains("Round 1 of 2"));
+    assert!(text.contains("bob wins round 1!"));
+    assert!(text.contains("alice wins round 2!"));
+    // 잘못된 입력 뒤에는 같은 사람에게 다시 묻는다.
+    let prompts: Vec<&str> = text
+        .lines()
+        .filter(|line| line.ends_with("please input your guess."))
+        .collect();
+    assert_eq!(
+        prompts,
+        vec![
+            "alice, please input your guess.",
+            "bob, please input your guess.",
+            "bob, please input your guess.",
+            "alice, please input your guess.",
+            "alice, please input your guess.",
+        ]
+    );
+    assert!(text.contains("alice: 1 of 2 rounds won (50%), 2 attempts"));
+    assert!(text.contains("The match is a draw between alice, bob."));
+}
+
+#[test]
+fn running_out_of_attempts_means_nobody_wins() {
+    let (report, text) = run(1, &[42], "1\n2\n3\n4\n");
+    assert_eq!(report.rounds[0].winner, None);
+    assert_eq!(report.rounds[0].attempts, vec![2, 2]);
+    assert!(text.contains("Nobody wins round 1."));
+    assert!(report.leaders().is_empty());
+}
+
+#[test]
+fn quitting_stops_the_match_and_keeps_finished_rounds() {
+    let (report, text) = run(3, &[42, 70, 5], "42\nquit\n");
+    assert!(!report.finished);
+    assert_eq!(report.rounds.len(), 1);
+    assert_eq!(report.leaders(), vec!["alice"]);
+    assert!(text.contains("alice wins the match!"));
+}
+
+#[test]
+fn players_flag_is_validated() {
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+    let options = parse(&["--players", "alice, bob", "--rounds", "5"]).unwrap();
+    assert_eq!(options.players, players());
+    assert_eq!(options.rounds, Some(5));
+
+    assert!(parse(&["--players", "alice"]).is_err());
+    assert!(parse(&["--players", "alice,,bob"]).is_err());
+    assert!(parse(&["--players", "alice,alice"]).is_err());
+    assert!(parse(&["--rounds", "2"]).is_err());
+    assert!(parse(&["--players", "a,b", "--rounds", "0"]).is_err());
+    assert!(parse(&["--players", "a,b", "--json"]).is_err());
+}
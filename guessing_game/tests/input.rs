@@ -0,0 +1,68 @@
+use guessing_game::input::{parse_input, Command, Input, InputError};
+use guessing_game::{play, Difficulty, GameEngine, Outcome};
+
+fn parse(line: &str) -> Result<Input, InputError> {
+    parse_input(line, Difficulty::Normal.range())
+}
+
+#[test]
+fn classifies_rejected_input() {
+    assert_eq!(parse("\n"), Err(InputError::Empty));
+    assert_eq!(parse("   "), Err(InputError::Empty));
+    assert_eq!(
+        parse("abc\n"),
+        Err(InputError::NotANumber("abc".to_string()))
+    );
+    assert_eq!(parse("-5\n"), Err(InputError::Negative("-5".to_string())));
+    assert_eq!(
+        parse("99999999999\n"),
+        Err(InputError::TooLarge("99999999999".to_string()))
+    );
+    assert_eq!(
+        parse("101\n"),
+        Err(InputError::OutOfRange {
+            value: 101,
+            range: Difficulty::Normal.range()
+        })
+    );
+    assert_eq!(parse(" 42 \n"), Ok(Input::Guess(42)));
+}
+
+#[test]
+fn recognizes_commands() {
+    assert_eq!(parse("quit\n"), Ok(Input::Command(Command::Quit)));
+    assert_eq!(parse("HINT\n"), Ok(Input::Command(Command::Hint)));
+    assert_eq!(parse("history\n"), Ok(Input::Command(Command::History)));
+}
+
+#[test]
+fn session_explains_each_rejection() {
+    let mut engine = GameEngine::new(42, Difficulty::Normal.range());
+    let mut output = Vec::new();
+    let script = "\nabc\n-5\n99999999999\n0\n42\n";
+    let outcome = play(&mut engine, script.as_bytes(), &mut output).unwrap();
+    assert_eq!(outcome, Outcome::Won { attempts: 1 });
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("Please type a number.\n"));
+    assert!(output.contains("'abc' is not a number."));
+    assert!(output.contains("-5 is negative."));
+    assert!(output.contains("99999999999 is way too large.\n"));
+    assert!(output.contains("Please guess a number between 1 and 100.\n"));
+}
+
+#[test]
+fn commands_work_at_the_prompt() {
+    let mut engine = GameEngine::new(42, Difficulty::Normal.range()).with_hints(1);
+    let mut output = Vec::new();
+    let script = "history\n50\n30\nhint\nhint\nhistory\nquit\n42\n";
+    let outcome = play(&mut engine, script.as_bytes(), &mut output).unwrap();
+    assert_eq!(outcome, Outcome::Abandoned { attempts: 2 });
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("No guesses yet.\n"));
+    assert!(output.contains("Hint: the secret number is between 41 and 49. (0 hints left)\n"));
+    assert!(output.contains("No hints left.\n"));
+    assert!(output.contains("  1. 50 (too big)\n  2. 30 (too small)\n"));
+    assert!(output.ends_with("Bye!\n"));
+}
@@ -0,0 +1,183 @@
+use guessing_game::cli::Options;
+use guessing_game::difficulty::GuessRange;
+use guessing_game::messages::{bundle, Lang, Messages};
+use guessing_game::{play_game, BullsCowsEngine, GameEngine, Outcome};
+
+// 문장 안의 {이름} 자리들을 순서대로 모은다.
+fn placeholders(text: &str) -> Vec<&str> {
+    let mut names: Vec<&str> = text
+        .split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split('}').next())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn every_key_exists_in_every_bundle() {
+    let english = bundle(Lang::En);
+    for lang in Lang::ALL {
+        let other = bundle(lang);
+        assert_eq!(
+            other.len(),
+            english.len(),
+            "{} has extra or missing keys",
+            lang
+        );
+        for (key, text) in english {
+            let translated = other
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, text)| *text)
+                .unwrap_or_else(|| panic!("{} is missing {}", lang, key));
+            // 번역에서 {이름} 자리가 빠지거나 바뀌면 값이 채워지지 않는다.
+            assert_eq!(
+                placeholders(translated),
+                placeholders(text),
+                "{}: {}",
+                lang,
+                key
+            );
+        }
+    }
+}
+
+#[test]
+fn output_outside_the_games_is_in_the_catalog() {
+    // 점수표, 서버, replay, bench에서 쓰는 문장도 모든 언어에 있어야 한다.
+    let keys = [
+        "game_over",
+        "server.welcome",
+        "server.joined",
+        "server.left",
+        "server.round",
+        "server.won",
+        "server.hint",
+        "server.history",
+        "server.round_over",
+        "server.serving",
+        "scores.empty",
+        "scores.header",
+        "scores.players_header",
+        "replay.ok",
+        "replay.mismatches",
+        "replay.step",
+        "replay.expected",
+        "replay.actual",
+        "bench.header",
+        "bench.summary",
+        "bench.attempts",
+    ];
+    for lang in Lang::ALL {
+        for key in keys {
+            assert!(
+                bundle(lang).iter().any(|(name, _)| *name == key),
+                "{} is missing {}",
+                lang,
+                key
+            );
+        }
+    }
+}
+
+#[test]
+fn bundles_have_no_duplicate_keys() {
+    for lang in Lang::ALL {
+        let mut keys: Vec<&str> = bundle(lang).iter().map(|(key, _)| *key).collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), bundle(lang).len(), "{}", lang);
+    }
+}
+
+#[test]
+fn format_fills_placeholders() {
+    let en = Messages::new(Lang::En);
+    assert_eq!(
+        en.format("attempts_left", &[("left", &3)]),
+        "Attempts left: 3"
+    );
+    let ko = Messages::new(Lang::Ko);
+    assert_eq!(ko.format("attempts_left", &[("left", &3)]), "남은 기회: 3");
+    // 없는 키는 키 자체를 보여준다.
+    assert_eq!(en.get("no.such.key"), "no.such.key");
+}
+
+#[test]
+fn substituted_values_are_not_expanded_again() {
+    let en = Messages::new(Lang::En);
+    assert_eq!(
+        en.format(
+            "hotseat.player",
+            &[
+                ("player", &"{attempts}"),
+                ("wins", &1),
+                ("rounds", &2),
+                ("rate", &50),
+                ("attempts", &7),
+            ]
+        ),
+        "{attempts}: 1 of 2 rounds won (50%), 7 attempts"
+    );
+    // 값이 없는 자리는 그대로 남는다.
+    assert_eq!(en.format("attempts_left", &[]), "Attempts left: {left}");
+}
+
+#[test]
+fn lang_from_locale_and_flag() {
+    assert_eq!(Lang::from_locale("ko_KR.UTF-8"), Some(Lang::Ko));
+    assert_eq!(Lang::from_locale("en-US"), Some(Lang::En));
+    assert_eq!(Lang::from_locale("C"), None);
+    assert_eq!(Lang::from_locale(""), None);
+
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+    assert_eq!(parse(&["--lang", "ko"]).unwrap().lang, Some(Lang::Ko));
+    assert_eq!(parse(&[]).unwrap().lang, None);
+    assert!(parse(&["--lang", "fr"]).is_err());
+}
+
+#[test]
+fn korean_number_game() {
+    let range = GuessRange::new(1, 100).unwrap();
+    let mut engine = GameEngine::new(42, range)
+        .with_max_attempts(5)
+        .with_lang(Lang::Ko);
+    let mut output = Vec::new();
+    let outcome = play_game(
+        &mut engine,
+        "abc\n50\nhistory\n42\n".as_bytes(),
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(outcome, Outcome::Won { attempts: 2 });
+
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains("숫자를 맞혀보세요!"));
+    assert!(text.contains("비밀 숫자는 1부터 100 사이에 있습니다."));
+    assert!(text.contains("'abc'은(는) 숫자가 아닙니다."));
+    assert!(text.contains("너무 큽니다!"));
+    assert!(text.contains("  1. 50 (큼)"));
+    assert!(text.contains("정답입니다!"));
+    assert!(!text.contains("Too big!"));
+}
+
+#[test]
+fn korean_bulls_and_cows() {
+    let mut engine = BullsCowsEngine::new(vec![1, 2, 3])
+        .with_max_attempts(1)
+        .with_lang(Lang::Ko);
+    let mut output = Vec::new();
+    let outcome = play_game(&mut engine, "132\n".as_bytes(), &mut output).unwrap();
+    assert_eq!(
+        outcome,
+        Outcome::Lost {
+            attempts: 1,
+            secret: "123".to_string()
+        }
+    );
+
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains("1 스트라이크, 2 볼"));
+    assert!(text.contains("졌습니다! 정답은 123입니다."));
+}
@@ -0,0 +1,133 @@
+use guessing_game::cli::Options;
+use guessing_game::difficulty::GuessRange;
+use guessing_game::messages::Lang;
+use guessing_game::protocol::play_json;
+use guessing_game::{GameEngine, Outcome};
+use serde_json::{json, Value};
+
+// 스크립트를 보내고, 출력된 줄들을 JSON으로 파싱해서 돌려준다.
+fn run(engine: &mut GameEngine, script: &str) -> (Outcome, Vec<Value>) {
+    let mut output = Vec::new();
+    let outcome = play_json(engine, script.as_bytes(), &mut output).unwrap();
+    let events = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    (outcome, events)
+}
+
+fn engine() -> GameEngine {
+    GameEngine::new(42, GuessRange::new(1, 100).unwrap())
+        .with_max_attempts(3)
+        .with_hints(1)
+}
+
+#[test]
+fn start_guesses_and_win() {
+    let mut engine = engine();
+    let (outcome, events) = run(&mut engine, "{\"guess\": 50}\n{\"guess\": \"42\"}\n");
+    assert_eq!(outcome, Outcome::Won { attempts: 2 });
+    assert_eq!(
+        events,
+        vec![
+            json!({"event": "start", "min": 1, "max": 100, "max_attempts": 3, "hints": 1}),
+            json!({"result": "too_big", "guess": 50, "attempt": 1, "attempts_left": 2}),
+            json!({"result": "correct", "guess": 42, "attempt": 2, "attempts_left": 1}),
+            json!({"event": "won", "attempts": 2}),
+        ]
+    );
+}
+
+#[test]
+fn errors_are_reported_and_game_continues() {
+    let mut engine = engine();
+    let script = "not json\n{\"guess\": 500}\n{\"guess\": \"abc\"}\n{\"guess\": -3}\n\
+                  {\"guess\": 1, \"command\": \"hint\"}\n{\"command\": \"dance\"}\n{\"foo\": 1}\n";
+    let (outcome, events) = run(&mut engine, script);
+    assert_eq!(outcome, Outcome::Abandoned { attempts: 0 });
+
+    let errors: Vec<&str> = events
+        .iter()
+        .filter_map(|event| event["error"].as_str())
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            "invalid_json",
+            "out_of_range",
+            "not_a_number",
+            "negative",
+            "invalid_request",
+            "unknown_command",
+            "invalid_json",
+        ]
+    );
+    assert_eq!(
+        events.last().unwrap(),
+        &json!({"event": "abandoned", "attempts": 0})
+    );
+}
+
+#[test]
+fn hint_history_and_quit() {
+    let mut engine = engine();
+    let script = "{\"guess\": 10}\n{\"command\": \"hint\"}\n{\"command\": \"hint\"}\n\
+                  {\"command\": \"history\"}\n{\"command\": \"quit\"}\n{\"guess\": 42}\n";
+    let (outcome, events) = run(&mut engine, script);
+    assert_eq!(outcome, Outcome::Abandoned { attempts: 1 });
+    assert_eq!(events[2]["hints_left"], json!(0));
+    assert!(events[2]["hint"]["min"].is_u64());
+    assert_eq!(events[3]["error"], json!("no_hints_left"));
+    assert_eq!(
+        events[4],
+        json!({"history": [{"guess": 10, "result": "too_small"}]})
+    );
+    // quit 다음 줄은 읽지 않는다.
+    assert_eq!(events.len(), 6);
+}
+
+#[test]
+fn losing_reveals_secret_and_proximity_is_reported() {
+    let mut engine = engine().with_hot_cold();
+    let script = "{\"guess\": 1}\n{\"guess\": 2}\n{\"guess\": 3}\n";
+    let (outcome, events) = run(&mut engine, script);
+    assert_eq!(
+        outcome,
+        Outcome::Lost {
+            attempts: 3,
+            secret: "42".to_string()
+        }
+    );
+    assert_eq!(events[1]["proximity"], "freezing");
+    assert_eq!(
+        events.last().unwrap(),
+        &json!({"event": "lost", "attempts": 3, "secret": 42})
+    );
+}
+
+#[test]
+fn json_flag_is_validated() {
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+    assert!(parse(&["--json"]).unwrap().json);
+    assert!(parse(&["--json", "--mode", "bulls-cows"]).is_err());
+    assert!(parse(&["--json", "--record", "log.txt"]).is_err());
+}
+
+#[test]
+fn guesses_after_the_game_is_over_are_errors() {
+    let mut engine = engine();
+    engine.guess(42).unwrap();
+    let (outcome, events) = run(&mut engine, "{\"guess\": 50}\n{\"guess\": 60}\n");
+    assert_eq!(outcome, Outcome::Won { attempts: 1 });
+    assert_eq!(events[1]["error"], "game_over");
+    assert_eq!(events[2], json!({"event": "won", "attempts": 1}));
+    assert_eq!(events.len(), 3);
+}
+
+#[test]
+fn proximity_names_do_not_follow_the_language() {
+    let mut engine = engine().with_hot_cold().with_lang(Lang::Ko);
+    let (_, events) = run(&mut engine, "{\"guess\": 40}\n");
+    assert_eq!(events[1]["proximity"], "burning");
+}
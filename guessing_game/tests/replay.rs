@@ -0,0 +1,124 @@
+use guessing_game::cli::{GameMode, Options};
+use guessing_game::game::play_with;
+use guessing_game::replay::{replay, Recorder, SessionLog};
+use guessing_game::session::SessionConfig;
+use guessing_game::Outcome;
+
+fn config(args: &[&str], seed: u64) -> SessionConfig {
+    let options = Options::parse(args.iter().map(|arg| arg.to_string())).unwrap();
+    SessionConfig::from_options(&options, seed)
+}
+
+// 스크립트로 한 판을 하면서 기록한 로그를 문자열로 돌려준다.
+fn record(config: &SessionConfig, script: &str) -> (Outcome, String) {
+    let mut log = Vec::new();
+    let mut recorder = Recorder::new(&mut log, config).unwrap();
+    let mut game = config.build();
+    let outcome = play_with(
+        &mut *game,
+        script.as_bytes(),
+        Vec::new(),
+        |game, line, out| recorder.record_step(game, line, out),
+    )
+    .unwrap();
+    recorder.record_outcome(&outcome).unwrap();
+    (outcome, String::from_utf8(log).unwrap())
+}
+
+#[test]
+fn recorded_session_replays_cleanly() {
+    let config = config(&["--hot-cold"], 7);
+    let (outcome, text) = record(&config, "50\nabc\nhint\n25\nhistory\nquit\n");
+    assert_eq!(outcome, Outcome::Abandoned { attempts: 2 });
+
+    let log = SessionLog::parse(&text).unwrap();
+    assert_eq!(log.config, config);
+    assert_eq!(log.events.len(), 6);
+    assert_eq!(log.events[0].input, "50");
+    assert_eq!(log.events[0].guess, Some("50".to_string()));
+    assert_eq!(log.events[1].guess, None);
+    assert!(log.events[1].feedback[0].contains("'abc' is not a number."));
+
+    let report = replay(&log).unwrap();
+    assert_eq!(report.steps, 6);
+    assert!(report.is_ok(), "{:?}", report.mismatches);
+}
+
+#[test]
+fn bulls_and_cows_sessions_replay_too() {
+    let config = config(&["--mode", "bulls-cows", "--digits", "3"], 11);
+    assert_eq!(config.game, GameMode::BullsCows);
+    let (_, text) = record(&config, "123\n456\n789\n");
+    let log = SessionLog::parse(&text).unwrap();
+    assert!(replay(&log).unwrap().is_ok());
+}
+
+#[test]
+fn tampered_feedback_is_reported() {
+    let config = config(&[], 3);
+    let (_, text) = record(&config, "50\n");
+    let tampered = text
+        .replace("Too small!", "Too big?")
+        .replace("Too big!", "Too small?");
+
+    let report = replay(&SessionLog::parse(&tampered).unwrap()).unwrap();
+    assert_eq!(report.mismatches.len(), 1);
+    assert_eq!(report.mismatches[0].step, 1);
+    assert_eq!(report.mismatches[0].input, "50");
+}
+
+#[test]
+fn a_different_seed_changes_the_outcome() {
+    let (_, text) = record(&config(&["--easy"], 1), "1\n2\n3\n4\n5\n");
+    let other = text.replace("seed\t1\n", "seed\t2\n");
+    let replayed = replay(&SessionLog::parse(&text).unwrap()).unwrap();
+    let diverged = replay(&SessionLog::parse(&other).unwrap()).unwrap();
+    assert!(replayed.is_ok());
+    assert!(!diverged.is_ok());
+}
+
+#[test]
+fn rejects_malformed_logs() {
+    assert!(SessionLog::parse("hello\n").is_err());
+    assert!(SessionLog::parse("guessing_game-log\t1\nseed\t1\n").is_err());
+    let (_, text) = record(&config(&[], 1), "50\n");
+    let broken = text.replace("input\t", "input\tsoon\t");
+    assert!(SessionLog::parse(&broken).is_err());
+}
+
+#[test]
+fn rejects_corrupt_settings_instead_of_panicking() {
+    let (_, text) = record(&config(&["--mode", "bulls-cows"], 1), "1234\n");
+    for (from, to) in [
+        ("digits\t4\n", "digits\t11\n"),
+        ("digits\t4\n", "digits\t0\n"),
+        ("digits\t4\n", "digits\t18446744073709551616\n"),
+        ("max_attempts\t10\n", "max_attempts\t4294967296\n"),
+        ("hints\t2\n", "hints\t4294967296\n"),
+    ] {
+        let corrupt = text.replace(from, to);
+        assert_ne!(corrupt, text);
+        assert!(SessionLog::parse(&corrupt).is_err(), "{}", to);
+    }
+
+    let (_, text) = record(&config(&["--mode", "hangman"], 1), "e\n");
+    let word = text
+        .lines()
+        .find(|line| line.starts_with("word\t"))
+        .unwrap()
+        .to_string();
+    let corrupt = text.replace(&word, "word\t123-");
+    let err = SessionLog::parse(&corrupt).unwrap_err();
+    assert!(err.contains("no letters"), "{}", err);
+}
+
+#[test]
+fn parses_replay_subcommand() {
+    let args = ["replay", "session.log"];
+    let options = Options::parse(args.iter().map(|arg| arg.to_string())).unwrap();
+    assert_eq!(
+        options.replay_file.as_deref(),
+        Some(std::path::Path::new("session.log"))
+    );
+    assert!(Options::parse(vec!["replay".to_string()]).is_err());
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+
+use guessing_game::cli::{GameMode, Options};
+use guessing_game::difficulty::GuessRange;
+use guessing_game::game::step;
+use guessing_game::input::{parse_reply, Input, InputError};
+use guessing_game::reverse::{Bound, Contradiction, Guesser};
+use guessing_game::solver::Linear;
+use guessing_game::{play_game, Outcome};
+
+fn guesser() -> Guesser {
+    Guesser::new(GuessRange::new(1, 100).unwrap())
+}
+
+#[test]
+fn replies_map_to_orderings() {
+    assert_eq!(parse_reply("higher\n"), Ok(Input::Guess(Ordering::Greater)));
+    assert_eq!(parse_reply(" L "), Ok(Input::Guess(Ordering::Less)));
+    assert_eq!(parse_reply("Correct"), Ok(Input::Guess(Ordering::Equal)));
+    assert!(matches!(parse_reply("history"), Ok(Input::Command(_))));
+    assert_eq!(
+        parse_reply("maybe"),
+        Err(InputError::NotAnAnswer("maybe".to_string()))
+    );
+}
+
+#[test]
+fn honest_answers_find_the_number() {
+    // 사람이 생각한 숫자는 73이다.
+    let mut guesser = guesser();
+    let mut answers = 0;
+    while let Some(guess) = guesser.current_guess() {
+        answers += 1;
+        assert!(answers <= 7, "binary search needs at most 7 guesses");
+        guesser.answer(73.cmp(&guess)).unwrap();
+    }
+    assert!(guesser.is_found());
+    assert_eq!(guesser.history().last(), Some(&(73, Ordering::Equal)));
+}
+
+#[test]
+fn contradicting_answers_are_detected() {
+    let mut guesser = guesser();
+    // 50보다 크다고 해놓고 51보다 작다고 하면 남는 숫자가 없다.
+    assert_eq!(guesser.answer(Ordering::Greater), Ok(Some(75)));
+    assert_eq!(guesser.answer(Ordering::Less), Ok(Some(62)));
+    assert_eq!(guesser.answer(Ordering::Less), Ok(Some(56)));
+    assert_eq!(guesser.answer(Ordering::Less), Ok(Some(53)));
+    assert_eq!(guesser.answer(Ordering::Less), Ok(Some(51)));
+    let contradiction = Contradiction {
+        above: Bound::Answer { step: 1, guess: 50 },
+        below: Bound::Answer { step: 6, guess: 51 },
+    };
+    assert_eq!(guesser.answer(Ordering::Less), Err(contradiction.clone()));
+    assert!(guesser.is_over());
+    assert_eq!(guesser.current_guess(), None);
+    // 끝난 뒤에 대답해도 같은 모순을 알려준다.
+    assert_eq!(guesser.answer(Ordering::Equal), Err(contradiction));
+}
+
+#[test]
+fn answers_outside_the_range_are_contradictions() {
+    let range = GuessRange::new(1, 10).unwrap();
+    let mut guesser = Guesser::with_strategy(range, Box::new(Linear));
+    assert_eq!(guesser.current_guess(), Some(1));
+    assert_eq!(
+        guesser.answer(Ordering::Less),
+        Err(Contradiction {
+            above: Bound::Range(1),
+            below: Bound::Answer { step: 1, guess: 1 },
+        })
+    );
+}
+
+#[test]
+fn cheating_is_called_out_in_the_game() {
+    let mut guesser = guesser();
+    let mut output = Vec::new();
+    let script = "higher
+what
+lower
+lower
+lower
+lower
+history
+lower
+";
+    let outcome = play_game(&mut guesser, script.as_bytes(), &mut output).unwrap();
+    assert_eq!(outcome, Outcome::Cheated { attempts: 6 });
+
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains("Think of a number between 1 and 100, and I'll guess it."));
+    assert!(text.contains("My guess: 50"));
+    assert!(text.contains("Is your number higher, lower, or correct?"));
+    assert!(text.contains("'what' is not an answer."));
+    assert!(text.contains("  1. 50 (higher)"));
+    assert!(text.contains("You're cheating! No number fits these answers:"));
+    assert!(text.contains("  #1: higher than 50"));
+    assert!(text.contains("  #6: lower than 51"));
+}
+
+#[test]
+fn correct_answer_ends_the_game() {
+    let mut guesser = guesser();
+    let mut output = Vec::new();
+    let outcome = play_game(
+        &mut guesser,
+        "lower
+correct
+"
+        .as_bytes(),
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(outcome, Outcome::Won { attempts: 2 });
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains("I found your number 25 in 2 guesses!"));
+}
+
+#[test]
+fn input_after_the_game_is_over_is_refused() {
+    let mut guesser = guesser();
+    let mut output = Vec::new();
+    assert!(step(&mut guesser, "correct\n", &mut output)
+        .unwrap()
+        .is_some());
+
+    let mut output = Vec::new();
+    assert_eq!(step(&mut guesser, "higher\n", &mut output).unwrap(), None);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "The game is already over.\n"
+    );
+}
+
+#[test]
+fn reverse_mode_is_selectable() {
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+    assert_eq!(
+        parse(&["--mode", "reverse"]).unwrap().game,
+        GameMode::Reverse
+    );
+    assert!(parse(&["--mode", "reverse", "--players", "a,b"]).is_err());
+}
@@ -0,0 +1,176 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use guessing_game::messages::{Lang, Messages};
+use guessing_game::score::{score, Performance};
+use guessing_game::scores::{ScoreEntry, ScoreTable, MAX_ENTRIES};
+use guessing_game::Difficulty;
+
+fn entry(name: &str, points: u32) -> ScoreEntry {
+    ScoreEntry {
+        name: name.to_string(),
+        score: points,
+        attempts: 5,
+        hints_used: 0,
+        difficulty: "normal".to_string(),
+        elapsed_secs: 10,
+        recorded_at: 0,
+    }
+}
+
+// 테스트마다 겹치지 않는 임시 디렉토리를 만든다.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("guessing_game-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn performance(difficulty: Difficulty, attempts: u32, secs: u64) -> Performance {
+    Performance {
+        base: difficulty.range().binary_search_steps() * 100,
+        attempts,
+        max_attempts: difficulty.max_attempts(),
+        hints_used: 0,
+        hot_cold: false,
+        elapsed: Duration::from_secs(secs),
+    }
+}
+
+#[test]
+fn fewer_attempts_and_less_time_score_higher() {
+    let quick = score(&performance(Difficulty::Normal, 3, 5));
+    let slow = score(&performance(Difficulty::Normal, 3, 60));
+    let many = score(&performance(Difficulty::Normal, 9, 5));
+    assert!(quick > slow);
+    assert!(quick > many);
+    assert_eq!(score(&performance(Difficulty::Normal, 10, 100_000)), 0);
+}
+
+#[test]
+fn harder_ranges_score_higher() {
+    let easy = score(&performance(Difficulty::Easy, 3, 5));
+    let hard = score(&performance(Difficulty::Hard, 3, 5));
+    assert!(hard > easy);
+}
+
+#[test]
+fn hints_and_hot_cold_cost_points() {
+    let plain = performance(Difficulty::Normal, 4, 5);
+    let hinted = Performance {
+        hints_used: 2,
+        ..plain.clone()
+    };
+    let hot_cold = Performance {
+        hot_cold: true,
+        ..plain.clone()
+    };
+    assert_eq!(score(&plain) - score(&hinted), 200);
+    assert!(score(&hot_cold) < score(&plain));
+}
+
+#[test]
+fn table_keeps_best_entries_in_order() {
+    let mut table = ScoreTable::default();
+    assert_eq!(table.insert(entry("a", 100)), Some(1));
+    assert_eq!(table.insert(entry("b", 300)), Some(1));
+    assert_eq!(table.insert(entry("c", 100)), Some(3));
+
+    for i in 0..MAX_ENTRIES {
+        table.insert(entry("filler", 200 + i as u32));
+    }
+    assert_eq!(table.entries().len(), MAX_ENTRIES);
+    assert_eq!(table.insert(entry("late", 1)), None);
+    assert_eq!(table.entries()[0].name, "b");
+}
+
+#[test]
+fn table_round_trips_through_file() {
+    let dir = temp_dir("scores");
+    let path = dir.join("nested").join("scores.json");
+
+    assert_eq!(ScoreTable::load(&path).unwrap(), ScoreTable::default());
+
+    let mut table = ScoreTable::default();
+    table.insert(entry("alice", 420));
+    table.save(&path).unwrap();
+
+    assert_eq!(ScoreTable::load(&path).unwrap(), table);
+    // 임시 파일은 남지 않는다.
+    assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn concurrent_saves_do_not_share_a_temp_file() {
+    let dir = temp_dir("concurrent");
+    let path = dir.join("scores.json");
+
+    // 여러 스레드가 같은 경로에 동시에 저장해도 임시 파일이 겹치지 않아서 모두 성공하고, 파일은 깨지지 않는다.
+    let writers: Vec<_> = (0..8)
+        .map(|i| {
+            let path = path.clone();
+            thread::spawn(move || {
+                let mut table = ScoreTable::default();
+                table.insert(entry(&format!("player{}", i), 100 + i));
+                for _ in 0..20 {
+                    table.save(&path).unwrap();
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+
+    assert_eq!(ScoreTable::load(&path).unwrap().entries().len(), 1);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn player_stats_are_saved_alongside_scores() {
+    let dir = temp_dir("players");
+    let path = dir.join("scores.json");
+
+    // 사람별 기록이 없던 예전 점수표도 읽을 수 있다.
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(&path, r#"{"entries": []}"#).unwrap();
+    let mut table = ScoreTable::load(&path).unwrap();
+    assert_eq!(table.player("alice"), None);
+
+    table.record_round("alice", true, 3);
+    table.record_round("alice", false, 4);
+    let stats = table.record_round("alice", true, 2).clone();
+    assert_eq!(stats.rounds_played, 3);
+    assert_eq!(stats.rounds_won, 2);
+    assert_eq!(stats.attempts, 9);
+    assert_eq!(stats.win_rate(), 66);
+    table.save(&path).unwrap();
+
+    assert_eq!(
+        ScoreTable::load(&path).unwrap().player("alice"),
+        Some(&stats)
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn table_is_printed_in_the_chosen_language() {
+    let print = |table: &ScoreTable, lang| {
+        let mut output = Vec::new();
+        table.print(&Messages::new(lang), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    };
+    let mut table = ScoreTable::default();
+    assert_eq!(print(&table, Lang::En), "No high scores yet.\n");
+    assert_eq!(print(&table, Lang::Ko), "아직 최고 기록이 없습니다.\n");
+
+    table.insert(entry("ferris", 900));
+    let text = print(&table, Lang::Ko);
+    assert!(text.starts_with("순위  이름"));
+    assert!(text.contains("ferris"));
+}
@@ -0,0 +1,37 @@
+use guessing_game::cli::Options;
+use guessing_game::secret::{self, FixedSequence};
+use guessing_game::SecretSource;
+
+fn draw<S: SecretSource>(source: &mut S, count: usize) -> Vec<u32> {
+    (0..count).map(|_| source.next_secret(1, 100)).collect()
+}
+
+#[test]
+fn same_seed_yields_same_sequence() {
+    let first = draw(&mut secret::seeded(2024), 20);
+    let second = draw(&mut secret::seeded(2024), 20);
+    assert_eq!(first, second);
+    assert!(first.iter().all(|n| (1..=100).contains(n)));
+}
+
+#[test]
+fn different_seeds_diverge() {
+    assert_ne!(
+        draw(&mut secret::seeded(1), 20),
+        draw(&mut secret::seeded(2), 20)
+    );
+}
+
+#[test]
+fn fixed_sequence_cycles_and_clamps() {
+    let mut source = FixedSequence::new(vec![5, 0, 500]);
+    assert_eq!(draw(&mut source, 4), vec![5, 1, 100, 5]);
+}
+
+#[test]
+fn parses_seed_flag() {
+    let args = vec!["--seed".to_string(), "42".to_string()];
+    assert_eq!(Options::parse(args).unwrap().seed, Some(42));
+    assert!(Options::parse(vec!["--seed".to_string()]).is_err());
+    assert!(Options::parse(vec!["--seed".to_string(), "x".to_string()]).is_err());
+}
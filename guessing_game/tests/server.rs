@@ -0,0 +1,164 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use guessing_game::client;
+use guessing_game::messages::Lang;
+use guessing_game::secret::FixedSequence;
+use guessing_game::server::Server;
+use guessing_game::Difficulty;
+
+// 127.0.0.1의 빈 포트로 서버를 띄우고 주소를 돌려준다.
+fn start_server(secrets: Vec<u32>) -> SocketAddr {
+    let server = Server::bind(
+        "127.0.0.1:0",
+        Difficulty::Normal.range(),
+        Box::new(FixedSequence::new(secrets)),
+    )
+    .unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+    addr
+}
+
+struct Player {
+    stream: TcpStream,
+    reader: BufReader<TcpStream>,
+}
+
+impl Player {
+    fn join(addr: SocketAddr) -> Player {
+        let stream = TcpStream::connect(addr).unwrap();
+        let reader = BufReader::new(stream.try_clone().unwrap());
+        let mut player = Player { stream, reader };
+        assert!(player.read().starts_with("Welcome, player"));
+        player
+    }
+
+    fn send(&mut self, line: &str) {
+        writeln!(self.stream, "{}", line).unwrap();
+    }
+
+    fn read(&mut self) -> String {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).unwrap();
+        line.trim_end().to_string()
+    }
+}
+
+#[test]
+fn answers_each_guess() {
+    let addr = start_server(vec![42]);
+    let mut player = Player::join(addr);
+
+    player.send("10");
+    assert_eq!(player.read(), "Too small!");
+    player.send("90");
+    assert_eq!(player.read(), "Too big!");
+    player.send("abc");
+    assert!(player.read().starts_with("'abc' is not a number."));
+    player.send("42");
+    assert_eq!(player.read(), "You win!");
+    assert_eq!(
+        player.read(),
+        "Round 2: guess the number between 1 and 100."
+    );
+}
+
+#[test]
+fn first_correct_guesser_wins_the_round() {
+    let addr = start_server(vec![42, 7]);
+    let mut alice = Player::join(addr);
+    let mut bob = Player::join(addr);
+    assert_eq!(alice.read(), "Player 2 joined.");
+
+    alice.send("42");
+    assert_eq!(alice.read(), "You win!");
+    assert_eq!(bob.read(), "Player 1 guessed 42 and won round 1!");
+    assert_eq!(bob.read(), "Round 2: guess the number between 1 and 100.");
+    assert_eq!(alice.read(), "Round 2: guess the number between 1 and 100.");
+
+    // 새 라운드에서는 새 비밀 숫자를 맞춰야 한다.
+    bob.send("42");
+    assert_eq!(bob.read(), "Too big!");
+    bob.send("7");
+    assert_eq!(bob.read(), "You win!");
+    assert_eq!(alice.read(), "Player 2 guessed 7 and won round 2!");
+}
+
+#[test]
+fn disconnects_are_announced() {
+    let addr = start_server(vec![42]);
+    let mut alice = Player::join(addr);
+    let mut bob = Player::join(addr);
+    assert_eq!(alice.read(), "Player 2 joined.");
+
+    bob.send("quit");
+    assert_eq!(bob.read(), "Bye!");
+    assert_eq!(alice.read(), "Player 2 left.");
+
+    // 남은 사람은 계속 게임을 할 수 있다.
+    alice.send("42");
+    assert_eq!(alice.read(), "You win!");
+}
+
+#[test]
+fn replies_use_the_server_language() {
+    let server = Server::bind(
+        "127.0.0.1:0",
+        Difficulty::Normal.range(),
+        Box::new(FixedSequence::new(vec![42])),
+    )
+    .unwrap()
+    .with_lang(Lang::Ko);
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    let stream = TcpStream::connect(addr).unwrap();
+    let reader = BufReader::new(stream.try_clone().unwrap());
+    let mut player = Player { stream, reader };
+    assert_eq!(
+        player.read(),
+        "1번 플레이어님, 환영합니다! 1라운드: 1부터 100 사이의 숫자를 맞혀보세요."
+    );
+    player.send("10");
+    assert_eq!(player.read(), "너무 작습니다!");
+    player.send("hint");
+    assert_eq!(
+        player.read(),
+        "힌트: 비밀 숫자는 11부터 100 사이에 있습니다."
+    );
+    player.send("quit");
+    assert_eq!(player.read(), "안녕히 가세요!");
+}
+
+#[test]
+fn client_relays_lines_both_ways() {
+    let addr = start_server(vec![42]);
+    // output은 'static이어야 해서, 스레드가 끝난 뒤에도 읽을 수 있도록 Arc로 공유한다.
+    let buffer = Arc::new(Mutex::new(Vec::new()));
+    client::connect(
+        addr,
+        &b"50\n42\nquit\n"[..],
+        SharedBuffer(Arc::clone(&buffer)),
+    )
+    .unwrap();
+
+    let output = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+    assert!(output.starts_with("Welcome, player 1!"));
+    assert!(output.contains("Too big!\nYou win!\n"));
+    assert!(output.ends_with("Bye!\n"));
+}
+
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
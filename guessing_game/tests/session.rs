@@ -0,0 +1,104 @@
+use std::cmp::Ordering;
+
+use guessing_game::game::step;
+use guessing_game::{play, Difficulty, GameEngine, GuessError, GuessRange, Outcome};
+
+// 스크립트된 입력으로 한 판을 돌리고, 결과와 출력된 문자열을 돌려준다.
+fn run(secret_number: u32, script: &str) -> (Outcome, String) {
+    let mut engine = GameEngine::new(secret_number, Difficulty::Normal.range());
+    let mut output = Vec::new();
+    let outcome = play(&mut engine, script.as_bytes(), &mut output).unwrap();
+    (outcome, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn engine_compares_against_secret() {
+    let mut engine = GameEngine::new(42, Difficulty::Normal.range());
+    assert_eq!(engine.guess(10), Ok(Ordering::Less));
+    assert_eq!(engine.guess(90), Ok(Ordering::Greater));
+    assert!(!engine.is_won());
+    assert_eq!(engine.guess(42), Ok(Ordering::Equal));
+    assert!(engine.is_won());
+    assert_eq!(engine.attempts(), 3);
+}
+
+#[test]
+fn scripted_session_is_won() {
+    let (outcome, output) = run(42, "50\n25\n42\n");
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+    assert!(output.starts_with("Guess the number!\n"));
+    assert!(output.contains("You guessed: 50\nToo big!\n"));
+    assert!(output.contains("You guessed: 25\nToo small!\n"));
+    assert!(output.ends_with("You guessed: 42\nYou win!\n"));
+}
+
+#[test]
+fn unparsable_lines_do_not_count_as_attempts() {
+    let (outcome, _) = run(7, "abc\n\n7\n");
+    assert_eq!(outcome, Outcome::Won { attempts: 1 });
+}
+
+#[test]
+fn end_of_input_abandons_the_game() {
+    let (outcome, output) = run(42, "1\n");
+    assert_eq!(outcome, Outcome::Abandoned { attempts: 1 });
+    assert!(!output.contains("You win!"));
+}
+
+#[test]
+fn out_of_range_guesses_are_reported_not_compared() {
+    let range = GuessRange::new(1, 10).unwrap();
+    let mut engine = GameEngine::new(3, range);
+    assert_eq!(
+        engine.guess(11),
+        Err(GuessError::OutOfRange { guess: 11, range })
+    );
+    assert_eq!(engine.attempts(), 0);
+
+    let (outcome, output) = run(42, "0\n101\n42\n");
+    assert_eq!(outcome, Outcome::Won { attempts: 1 });
+    assert_eq!(
+        output
+            .matches("Please guess a number between 1 and 100.")
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn running_out_of_attempts_loses() {
+    let range = Difficulty::Normal.range();
+    let mut engine = GameEngine::new(42, range).with_max_attempts(2);
+    let mut output = Vec::new();
+    let outcome = play(&mut engine, &b"1\n2\n42\n"[..], &mut output).unwrap();
+    assert_eq!(
+        outcome,
+        Outcome::Lost {
+            attempts: 2,
+            secret: "42".to_string()
+        }
+    );
+    assert_eq!(engine.guess(42), Err(GuessError::GameOver));
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("You have 2 attempts.\n"));
+    assert!(output.contains("Attempts left: 1\n"));
+    assert!(output.ends_with("You lose! The secret number was 42.\n"));
+}
+
+#[test]
+fn input_after_the_game_is_over_is_refused() {
+    let mut engine = GameEngine::new(42, Difficulty::Normal.range());
+    let mut output = Vec::new();
+    assert_eq!(
+        step(&mut engine, "42\n", &mut output).unwrap(),
+        Some(Outcome::Won { attempts: 1 })
+    );
+
+    let mut output = Vec::new();
+    assert_eq!(step(&mut engine, "50\n", &mut output).unwrap(), None);
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .contains("The game is already over."));
+    assert_eq!(engine.attempts(), 1);
+}
@@ -0,0 +1,179 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use errors::config::{Config, ConfigError, Origin};
+use guessing_game::cli::{self, CliError, Options};
+use guessing_game::messages::Lang;
+use guessing_game::settings::{self, Settings};
+use guessing_game::{Difficulty, GuessRange};
+
+// 설정 파일 위에 환경 변수를 쌓는다.
+fn layers(text: &str, vars: &[(&str, &str)]) -> Result<Config, ConfigError> {
+    let file = Config::parse("guessing_game.toml", text)?;
+    let env = Config::from_env(
+        "GUESSING_GAME",
+        vars.iter().map(|(k, v)| (k.to_string(), v.to_string())),
+    );
+    Ok(file.merge(env))
+}
+
+fn load(text: &str, vars: &[(&str, &str)]) -> Result<Settings, ConfigError> {
+    Settings::from_config(&layers(text, vars)?)
+}
+
+// 테스트마다 겹치지 않는 임시 디렉토리를 만든다.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("guessing_game-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn parse(args: &[&str], config: &Config) -> Result<Options, CliError> {
+    Options::parse_with(args.iter().map(|arg| arg.to_string()), config)
+}
+
+#[test]
+fn file_sets_difficulty_and_tunables() {
+    let settings = load(
+        "difficulty = \"hard\"\nhints = 1\nlang = \"ko\"\nport = 9000\n",
+        &[],
+    )
+    .unwrap();
+    assert_eq!(settings.difficulty, Difficulty::Hard);
+    assert_eq!(settings.hints, Some(1));
+    assert_eq!(settings.lang, Some(Lang::Ko));
+    assert_eq!(settings.port, Some(9000));
+
+    // 아무것도 정하지 않으면 원래의 기본값과 같다.
+    assert_eq!(load("", &[]).unwrap(), Settings::default());
+}
+
+#[test]
+fn env_overrides_file_and_flags_override_both() {
+    let config = layers(
+        "difficulty = \"easy\"\nmax = 50\n",
+        &[("GUESSING_GAME_MAX", "80"), ("GUESSING_GAME_HINTS", "4")],
+    )
+    .unwrap();
+    let settings = Settings::from_config(&config).unwrap();
+    // min은 난이도(easy)의 값을 그대로 쓴다.
+    assert_eq!(settings.difficulty.range(), GuessRange::new(1, 80).unwrap());
+    assert_eq!(settings.hints, Some(4));
+
+    let options = parse(&[], &config).unwrap();
+    assert_eq!(options.difficulty.range(), GuessRange::new(1, 80).unwrap());
+    assert_eq!(options.hints, Some(4));
+
+    let options = parse(&["--min", "10", "--hints", "0"], &config).unwrap();
+    assert_eq!(options.difficulty.range(), GuessRange::new(10, 80).unwrap());
+    assert_eq!(options.hints, Some(0));
+
+    let options = parse(&["--hard"], &config).unwrap();
+    assert_eq!(options.difficulty, Difficulty::Hard);
+}
+
+#[test]
+fn flag_errors_name_the_flag_like_the_other_layers() {
+    let config = layers("min = 10\n", &[]).unwrap();
+    let err = match parse(&["--port", "lots"], &config).unwrap_err() {
+        CliError::Config(err) => err,
+        other => panic!("expected a config error, got {:?}", other),
+    };
+    assert_eq!(
+        err.to_string(),
+        "flag --port: port must be an integer between 0 and 65535, found \"lots\""
+    );
+
+    // 범위는 파일의 min과 인자의 max를 함께 보고, 인자의 위치를 알려준다.
+    match parse(&["--max", "5"], &config).unwrap_err() {
+        CliError::Config(ConfigError::InvalidValue { key, origin, .. }) => {
+            assert_eq!(key, "max");
+            assert_eq!(
+                origin,
+                Some(Origin::Flag {
+                    flag: "--max".to_string()
+                })
+            );
+        }
+        other => panic!("expected InvalidValue, got {:?}", other),
+    }
+
+    assert!(matches!(
+        parse(&["--lang", "fr"], &config),
+        Err(CliError::Config(ConfigError::InvalidValue { .. }))
+    ));
+    assert!(matches!(
+        parse(&["--port"], &config),
+        Err(CliError::Usage(_))
+    ));
+}
+
+#[test]
+fn errors_point_at_the_bad_value() {
+    let err = load("difficulty = \"hard\"\nmax = \"lots\"\n", &[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "guessing_game.toml:2: max must be an integer between 0 and 4294967295, found a string (\"lots\")"
+    );
+
+    let err = load("difficulty = \"insane\"\n", &[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "guessing_game.toml:1: invalid difficulty: unknown difficulty: insane (expected easy, normal or hard)"
+    );
+
+    let err = load("min = 10\n", &[("GUESSING_GAME_MAX", "5")]).unwrap_err();
+    match err {
+        ConfigError::InvalidValue { key, origin, .. } => {
+            assert_eq!(key, "max");
+            assert_eq!(
+                origin,
+                Some(Origin::Env {
+                    var: "GUESSING_GAME_MAX".to_string()
+                })
+            );
+        }
+        other => panic!("expected InvalidValue, got {:?}", other),
+    }
+
+    let err = load("dificulty = \"hard\"\n", &[]).unwrap_err();
+    assert!(matches!(err, ConfigError::UnknownKey { .. }));
+}
+
+#[test]
+fn config_flag_selects_the_file() {
+    let dir = temp_dir("settings");
+    let path = dir.join("game.toml");
+    fs::write(&path, "lang = \"ko\"\n").unwrap();
+
+    let args: Vec<String> = ["--easy", "--config", path.to_str().unwrap()]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    assert_eq!(cli::config_path(&args).unwrap(), Some(path.clone()));
+
+    let config = settings::load_config(Some(&path), Vec::new()).unwrap();
+    let options = Options::parse_with(args, &config).unwrap();
+    assert_eq!(options.lang, Some(Lang::Ko));
+    assert_eq!(options.config, Some(path));
+    assert_eq!(options.difficulty, Difficulty::Easy);
+
+    // 다른 인자의 값으로 쓰인 --config는 설정 파일을 고르는 것이 아니다.
+    let args = |list: &[&str]| -> Vec<String> { list.iter().map(|arg| arg.to_string()).collect() };
+    assert_eq!(
+        cli::config_path(&args(&["--name", "--config"])).unwrap(),
+        None
+    );
+    assert!(cli::config_path(&args(&["--config"])).is_err());
+
+    // 직접 고른 파일이 없으면 에러다.
+    let missing = dir.join("missing.toml");
+    assert!(matches!(
+        Settings::load(Some(&missing), Vec::new()),
+        Err(ConfigError::Io { .. })
+    ));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,94 @@
+use guessing_game::bench;
+use guessing_game::cli::{Mode, Options};
+use guessing_game::messages::{Lang, Messages};
+use guessing_game::secret::{self, FixedSequence};
+use guessing_game::solver::{solve, BiasedMidpoint, BinarySearch, Linear, Solved, StrategyKind};
+use guessing_game::{Difficulty, GameEngine};
+
+#[test]
+fn binary_search_finds_every_number_within_budget() {
+    for difficulty in &[Difficulty::Easy, Difficulty::Normal] {
+        let range = difficulty.range();
+        for secret_number in range.min()..=range.max() {
+            let mut engine =
+                GameEngine::new(secret_number, range).with_max_attempts(difficulty.max_attempts());
+            let solved = solve(&mut engine, &mut BinarySearch);
+            assert!(solved.won);
+            assert!(solved.attempts <= range.binary_search_steps());
+        }
+    }
+}
+
+#[test]
+fn linear_runs_out_of_attempts_on_large_numbers() {
+    let range = Difficulty::Normal.range();
+    let mut engine = GameEngine::new(3, range).with_max_attempts(10);
+    assert_eq!(
+        solve(&mut engine, &mut Linear),
+        Solved {
+            attempts: 3,
+            won: true
+        }
+    );
+
+    let mut engine = GameEngine::new(90, range).with_max_attempts(10);
+    assert_eq!(
+        solve(&mut engine, &mut Linear),
+        Solved {
+            attempts: 10,
+            won: false
+        }
+    );
+}
+
+#[test]
+fn biased_midpoint_stays_inside_the_interval() {
+    let mut engine = GameEngine::new(100, Difficulty::Normal.range());
+    let solved = solve(&mut engine, &mut BiasedMidpoint::new(1.0 / 3.0));
+    assert!(solved.won);
+}
+
+#[test]
+fn bench_reports_histogram() {
+    let mut source = FixedSequence::new(vec![50, 25, 1]);
+    let mut strategy = StrategyKind::Binary.build(None);
+    let report = bench::run(Difficulty::Normal, 3, &mut *strategy, &mut source);
+
+    assert_eq!(report.games, 3);
+    assert_eq!(report.wins, 3);
+    // 50은 첫번째, 25는 두번째, 1은 여섯번째 추측에서 맞춘다.
+    assert_eq!(report.max_attempts, 6);
+    assert_eq!(report.histogram.values().sum::<u32>(), 3);
+    assert!((report.mean_attempts() - 3.0).abs() < f64::EPSILON);
+
+    let mut output = Vec::new();
+    report.print(&Messages::new(Lang::En), &mut output).unwrap();
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .contains("mean attempts: 3.00"));
+
+    // 벤치 결과도 고른 언어로 출력한다.
+    let mut output = Vec::new();
+    report.print(&Messages::new(Lang::Ko), &mut output).unwrap();
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .contains("평균 시도: 3.00, 최대 시도: 6"));
+}
+
+#[test]
+fn seeded_random_strategy_is_reproducible() {
+    let run = || {
+        let mut strategy = StrategyKind::Random.build(Some(7));
+        bench::run(Difficulty::Hard, 50, &mut *strategy, &mut secret::seeded(7))
+    };
+    assert_eq!(run(), run());
+}
+
+#[test]
+fn parses_bench_subcommand() {
+    let args = ["bench", "--games", "10", "--strategy", "linear"];
+    let options = Options::parse(args.iter().map(|arg| arg.to_string())).unwrap();
+    assert_eq!(options.mode, Mode::Bench);
+    assert_eq!(options.games, Some(10));
+    assert_eq!(options.strategy, StrategyKind::Linear);
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use guessing_game::cli::Options;
+use guessing_game::clock::{Clock, ManualClock};
+use guessing_game::difficulty::GuessRange;
+use guessing_game::game::play_with;
+use guessing_game::timed::Timed;
+use guessing_game::{Game, GameEngine, Outcome};
+
+fn engine(max_attempts: u32) -> Box<dyn Game> {
+    let range = GuessRange::new(1, 100).unwrap();
+    Box::new(GameEngine::new(42, range).with_max_attempts(max_attempts))
+}
+
+// 한 줄을 처리할 때마다 delays에 적힌 만큼 시계를 돌린다.
+// delays[i]는 i+1번째 줄을 입력하기까지 걸린 시간이다.
+fn run(timed: &mut Timed, clock: &ManualClock, script: &str, delays: &[u64]) -> (Outcome, String) {
+    let mut delays = delays.iter().map(|secs| Duration::from_secs(*secs));
+    if let Some(first) = delays.next() {
+        clock.advance(first);
+    }
+    let mut output = Vec::new();
+    let outcome = play_with(timed, script.as_bytes(), &mut output, |_, _, _| {
+        if let Some(delay) = delays.next() {
+            clock.advance(delay);
+        }
+        Ok(())
+    })
+    .unwrap();
+    (outcome, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn manual_clock_only_moves_when_advanced() {
+    let clock = ManualClock::new();
+    let handle = clock.clone();
+    assert_eq!(clock.now(), Duration::ZERO);
+    handle.advance(Duration::from_millis(1500));
+    assert_eq!(clock.now(), Duration::from_millis(1500));
+}
+
+#[test]
+fn slow_guess_costs_an_attempt() {
+    let clock = ManualClock::new();
+    let mut timed =
+        Timed::new(engine(3), Box::new(clock.clone())).with_time_limit(Duration::from_secs(10));
+    let (outcome, text) = run(&mut timed, &clock, "50\n25\n42\n", &[3, 11, 2]);
+
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+    assert!(text.contains("You have 10 seconds for each guess."));
+    assert!(text.contains("Too slow! You took 11.00s, the limit is 10.00s."));
+    // 늦게 입력한 25는 비교하지 않는다.
+    assert!(!text.contains("You guessed: 25"));
+    assert!(text.contains("Attempts left: 1"));
+}
+
+#[test]
+fn running_out_of_time_can_lose_the_game() {
+    let clock = ManualClock::new();
+    let mut timed =
+        Timed::new(engine(2), Box::new(clock.clone())).with_time_limit(Duration::from_secs(5));
+    let (outcome, text) = run(&mut timed, &clock, "50\n42\n", &[1, 6]);
+
+    assert_eq!(
+        outcome,
+        Outcome::Lost {
+            attempts: 2,
+            secret: "42".to_string()
+        }
+    );
+    assert!(text.contains("You lose! The secret number was 42."));
+}
+
+#[test]
+fn commands_do_not_restart_the_countdown() {
+    let clock = ManualClock::new();
+    let mut timed =
+        Timed::new(engine(3), Box::new(clock.clone())).with_time_limit(Duration::from_secs(10));
+    let (_, text) = run(&mut timed, &clock, "history\n42\n", &[6, 6]);
+    assert!(text.contains("Too slow! You took 12.00s"));
+}
+
+#[test]
+fn speedrun_reports_splits() {
+    let clock = ManualClock::new();
+    let mut timed = Timed::new(engine(5), Box::new(clock.clone())).with_speedrun();
+    let (outcome, text) = run(&mut timed, &clock, "50\nabc\n25\n42\n", &[2, 1, 3, 4]);
+
+    assert_eq!(outcome, Outcome::Won { attempts: 3 });
+    let splits: Vec<(String, Duration, Duration)> = timed
+        .splits()
+        .iter()
+        .map(|split| (split.guess.clone(), split.split, split.total))
+        .collect();
+    let secs = Duration::from_secs;
+    assert_eq!(
+        splits,
+        vec![
+            ("50".to_string(), secs(2), secs(2)),
+            ("25".to_string(), secs(4), secs(6)),
+            ("42".to_string(), secs(4), secs(10)),
+        ]
+    );
+    assert!(text.contains("Speedrun! The clock is running."));
+    assert!(text.contains("1. 50  +2.00s  (2.00s)"));
+    assert!(text.contains("3. 42  +4.00s  (10.00s)"));
+    assert!(text.contains("Total time: 10.00s"));
+}
+
+#[test]
+fn timing_flags_are_validated() {
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+    let options = parse(&["--time-limit", "1.5", "--speedrun"]).unwrap();
+    assert_eq!(options.time_limit, Some(Duration::from_millis(1500)));
+    assert!(options.speedrun);
+    assert!(parse(&["--time-limit", "0"]).is_err());
+    assert!(parse(&["--time-limit", "soon"]).is_err());
+    assert_eq!(
+        parse(&["--time-limit", "1e30"]).unwrap_err().to_string(),
+        "--time-limit is too large, got 1e30"
+    );
+    assert!(parse(&["--speedrun", "--record", "log.txt"]).is_err());
+    assert!(parse(&["--time-limit", "5", "--json"]).is_err());
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use guessing_game::cli::Options;
+use guessing_game::clock::ManualClock;
+use guessing_game::messages::Lang;
+use guessing_game::tui::{interval_bar, Key, Tui};
+use guessing_game::{GameEngine, GuessRange, Outcome};
+
+fn tui(clock: &ManualClock) -> Tui {
+    let engine = GameEngine::new(42, GuessRange::new(1, 100).unwrap())
+        .with_max_attempts(7)
+        .with_hints(1);
+    Tui::new(engine, Box::new(clock.clone()))
+}
+
+fn type_line(tui: &mut Tui, line: &str) -> Option<Outcome> {
+    for c in line.chars() {
+        assert_eq!(tui.press(Key::Char(c)).unwrap(), None);
+    }
+    tui.press(Key::Enter).unwrap()
+}
+
+#[test]
+fn bar_marks_the_plausible_interval() {
+    let range = GuessRange::new(1, 100).unwrap();
+    assert_eq!(interval_bar(range, &(1..=100), 10), "[##########]");
+    assert_eq!(interval_bar(range, &(51..=70), 10), "[.....##...]");
+    assert_eq!(interval_bar(range, &(42..=42), 10), "[....#.....]");
+    // 범위보다 칸이 많으면 숫자 하나에 한 칸
+    let small = GuessRange::new(1, 5).unwrap();
+    assert_eq!(interval_bar(small, &(2..=3), 40), "[.##..]");
+}
+
+#[test]
+fn keys_build_a_line_and_enter_submits_it() {
+    let clock = ManualClock::new();
+    let mut tui = tui(&clock);
+    tui.press(Key::Char('5')).unwrap();
+    tui.press(Key::Char('9')).unwrap();
+    tui.press(Key::Backspace).unwrap();
+    tui.press(Key::Char('0')).unwrap();
+    assert_eq!(tui.input(), "50");
+
+    assert_eq!(tui.press(Key::Enter).unwrap(), None);
+    assert_eq!(tui.input(), "");
+    assert_eq!(tui.engine().history(), &[(50, std::cmp::Ordering::Greater)]);
+    assert!(tui.feedback().contains(&"Too big!".to_string()));
+
+    // 빈 줄에서 Enter를 눌러도 아무 일도 없다.
+    assert_eq!(tui.press(Key::Enter).unwrap(), None);
+    assert_eq!(tui.engine().attempts(), 1);
+}
+
+#[test]
+fn preview_updates_while_typing() {
+    let clock = ManualClock::new();
+    let mut tui = tui(&clock);
+    type_line(&mut tui, "50");
+
+    // 50이 너무 크다고 했으니 7은 될 수 있지만 75는 안 된다.
+    tui.press(Key::Char('7')).unwrap();
+    assert_eq!(tui.preview(), None);
+    tui.press(Key::Char('5')).unwrap();
+    assert_eq!(tui.preview(), Some("75 is already ruled out.".to_string()));
+    tui.press(Key::Char('x')).unwrap();
+    assert_eq!(
+        tui.preview(),
+        Some("'75x' is not a number. Type a number, or quit, hint, history.".to_string())
+    );
+    tui.press(Key::Backspace).unwrap();
+    tui.press(Key::Backspace).unwrap();
+    assert_eq!(tui.preview(), None);
+}
+
+#[test]
+fn screen_shows_history_interval_attempts_and_score() {
+    let clock = ManualClock::new();
+    let mut tui = tui(&clock);
+    type_line(&mut tui, "50");
+    type_line(&mut tui, "25");
+    clock.advance(Duration::from_secs(3));
+
+    let screen = tui.render(80).join("\n");
+    assert!(screen.contains("Possible: 26 - 49"));
+    assert!(screen.contains("Attempts left: 5"));
+    assert!(screen.contains("Hints left: 1"));
+    assert!(screen.contains("Time: 3s"));
+    assert!(screen.contains("      50  too big\n      25  too small"));
+    assert!(screen.ends_with("> \n\nEnter: guess, hint/history: commands, Esc: quit"));
+
+    // 다음 추측으로 맞히면 받을 점수: 700 + 남은 기회 4 * 50 - 3초 * 2
+    assert_eq!(tui.potential_score(), 894);
+    assert!(screen.contains("Score if you win now: 894"));
+
+    // 폭이 좁아도 줄이 넘치지 않는다.
+    assert!(tui.render(12).iter().all(|row| row.chars().count() <= 12));
+}
+
+#[test]
+fn winning_finishes_the_screen() {
+    let clock = ManualClock::new();
+    let mut tui = tui(&clock);
+    type_line(&mut tui, "hint");
+    assert!(tui.engine().hints_used() == 1);
+    assert_eq!(
+        type_line(&mut tui, "42"),
+        Some(Outcome::Won { attempts: 1 })
+    );
+    assert!(tui.is_finished());
+
+    let screen = tui.render(80);
+    assert_eq!(screen.last().unwrap(), "Press any key to exit.");
+    // 끝난 뒤의 키는 무시한다.
+    assert_eq!(tui.press(Key::Char('1')).unwrap(), None);
+    assert_eq!(tui.into_engine().attempts(), 1);
+}
+
+#[test]
+fn escape_quits_and_text_is_localized() {
+    let clock = ManualClock::new();
+    let engine = GameEngine::new(42, GuessRange::new(1, 100).unwrap()).with_lang(Lang::Ko);
+    let mut tui = Tui::new(engine, Box::new(clock));
+    assert!(tui.render(80).contains(&"가능한 범위: 1 - 100".to_string()));
+    assert_eq!(
+        tui.press(Key::Esc).unwrap(),
+        Some(Outcome::Abandoned { attempts: 0 })
+    );
+    assert_eq!(tui.feedback(), &["안녕히 가세요!".to_string()]);
+}
+
+#[test]
+fn tui_is_only_for_the_number_mode() {
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+    assert!(parse(&["--tui"]).unwrap().tui);
+    assert!(!parse(&[]).unwrap().tui);
+    assert!(parse(&["--tui", "--mode", "bulls-cows"]).is_err());
+    assert!(parse(&["--tui", "--json"]).is_err());
+    assert!(parse(&["--tui", "--speedrun"]).is_err());
+    assert!(parse(&["--tui", "--players", "a,b"]).is_err());
+}